@@ -52,7 +52,7 @@ use super::llm_client::{
     LlmClient, OllamaClientConfig, OpenAiClientConfig, OpenRouterClientConfig,
     VertexAnthropicClientConfig, XaiClientConfig, ZaiClientConfig,
 };
-use super::system_prompt::build_system_prompt;
+use super::prompt_style::{build_prompt, select_prompt_style};
 use super::tool_definitions::ToolConfig;
 use qbit_context::ContextManager;
 use qbit_core::runtime::{QbitRuntime, RuntimeEvent};
@@ -771,8 +771,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -933,8 +934,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -1081,8 +1083,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -1225,8 +1228,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -1358,8 +1362,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -1491,8 +1496,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -1624,8 +1630,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -1757,8 +1764,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -1890,8 +1898,9 @@ impl AgentBridge {
         let workspace_path = self.workspace.read().await;
         let agent_mode = *self.agent_mode.read().await;
         let memory_file_path = self.get_memory_file_path_dynamic().await;
+        let prompt_style = select_prompt_style(&self.model_name);
         let mut system_prompt =
-            build_system_prompt(&workspace_path, agent_mode, memory_file_path.as_deref());
+            build_prompt(prompt_style, &workspace_path, agent_mode, memory_file_path.as_deref());
         drop(workspace_path);
 
         // Inject Layer 1 session context if available
@@ -7,7 +7,9 @@
 use std::path::Path;
 
 use super::agent_mode::AgentMode;
-use super::system_prompt::{get_agent_mode_instructions, read_project_instructions};
+use super::system_prompt::{
+    apply_shared_placeholders, get_agent_mode_instructions, read_project_instructions,
+};
 
 /// Codex-style base prompt optimized for OpenAI models.
 ///
@@ -106,9 +108,11 @@ pub fn build_codex_style_prompt(
     let project_instructions = read_project_instructions(workspace_path, memory_file_path);
     let agent_mode_instructions = get_agent_mode_instructions(agent_mode);
 
-    CODEX_STYLE_BASE_PROMPT
-        .replace("{project_instructions}", &project_instructions)
-        .replace("{agent_mode_instructions}", &agent_mode_instructions)
+    apply_shared_placeholders(
+        CODEX_STYLE_BASE_PROMPT,
+        &project_instructions,
+        &agent_mode_instructions,
+    )
 }
 
 #[cfg(test)]
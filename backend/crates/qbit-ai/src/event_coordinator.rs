@@ -30,10 +30,20 @@
 //!     Some(transcript_writer.clone()),
 //! );
 //!
-//! // Emit events (fire-and-forget)
+//! // Emit events (fire-and-forget; the new event is dropped if the data
+//! // lane is full)
 //! handle.emit(AiEvent::Started { turn_id: "123".to_string() });
 //!
-//! // Mark frontend ready (flushes buffered events)
+//! // Emit without blocking, finding out if it was dropped
+//! if handle.try_emit(AiEvent::Started { turn_id: "124".to_string() }).is_err() {
+//!     // data lane was full; event was dropped
+//! }
+//!
+//! // Emit with backpressure - waits for room in the data lane
+//! handle.emit_async(AiEvent::Started { turn_id: "125".to_string() }).await;
+//!
+//! // Mark frontend ready (flushes buffered events) - control lane, never
+//! // blocked behind a backlog of stream deltas
 //! handle.mark_frontend_ready();
 //!
 //! // Register approval request (returns receiver for decision)
@@ -45,9 +55,42 @@
 //! // Query state (for debugging/testing)
 //! let state = handle.query_state().await;
 //! ```
-
-use std::collections::HashMap;
+//!
+//! # Coalescing
+//!
+//! Passing a `coalesce_interval` in [`EventCoordinatorConfig`] turns on
+//! throttled coalescing for high-frequency streaming events: `TextDelta`
+//! and `Reasoning` are appended into a per-turn accumulator instead of
+//! being emitted immediately, and flushed as a single merged envelope on a
+//! fixed cadence. Any other event (tool requests, approvals, `Started`/
+//! `Completed`) flushes the pending accumulator first so ordering and
+//! sequence numbers stay monotonic; `Shutdown` and `MarkFrontendReady`
+//! force a final flush rather than waiting for the next tick.
+//!
+//! # Task attribution
+//!
+//! `CoordinatorHandle::emit`/`try_emit`/`emit_async`/`register_approval`
+//! capture the calling tokio task's id (via the unstable
+//! `tokio::task::id()` API, a no-op outside `tokio_unstable` builds) and
+//! the active tracing span's id. These travel with the command into the
+//! coordinator, which tallies them into `CoordinatorState::task_activity`
+//! so `query_state().await` can report how many events and pending
+//! approvals each concurrent (often sub-agent) task owns.
+//!
+//! # Approval timeouts
+//!
+//! `register_approval`/`register_approval_with_timeout` accept an optional
+//! deadline (falling back to `EventCoordinatorConfig::default_approval_timeout`
+//! when none is given). If no decision arrives before it passes, the
+//! coordinator removes the pending entry, sends a synthesized
+//! `ApprovalDecision` (deferring to `runtime.is_interactive()`/
+//! `runtime.auto_approve()` for the default), and emits
+//! `AiEvent::ApprovalTimedOut` so the frontend learns the request lapsed
+//! instead of the caller waiting on the receiver forever.
+
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::sync::{mpsc, oneshot};
 
@@ -57,13 +100,112 @@ use qbit_core::runtime::{QbitRuntime, RuntimeEvent};
 
 use crate::transcript::TranscriptWriter;
 
-/// Commands that can be sent to the EventCoordinator.
+/// Default capacity of the bounded data lane carrying `EmitEvent` commands.
+///
+/// Sized for a burst of streaming deltas; once `DEFAULT_DATA_LANE_CAPACITY`
+/// events are in flight, `emit`/`try_emit` drop the new event and
+/// `emit_async` waits, rather than the lane growing without bound.
+pub const DEFAULT_DATA_LANE_CAPACITY: usize = 256;
+
+/// Default cap on how many events can pile up in `event_buffer` while
+/// `frontend_ready` is false.
+///
+/// Without a cap, a frontend that never signals ready (e.g. a crashed tab)
+/// would let the buffer grow forever.
+pub const DEFAULT_EVENT_BUFFER_CAP: usize = 4096;
+
+/// Default size of the replay ring buffer used by `replay_since`.
+///
+/// Sized to cover a reconnect shortly after a disconnect; older envelopes
+/// are evicted to bound memory rather than retaining an entire session's
+/// history.
+pub const DEFAULT_REPLAY_BUFFER_CAP: usize = 1024;
+
+/// What to do when the pre-ready event buffer is full and a new event needs
+/// to go into it.
+///
+/// The data lane does not consult this policy: when it is full,
+/// `emit`/`try_emit` always drop the new event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Reject the newest event; whatever's already buffered is kept.
+    #[default]
+    DropNewest,
+    /// Discard the oldest buffered event to make room for the
+    /// newest one.
+    DropOldest,
+}
+
+/// Returned by [`CoordinatorHandle::try_emit`] when the data lane was full
+/// and the event could not be queued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmitOverflow;
+
+impl std::fmt::Display for EmitOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "event coordinator data lane is full")
+    }
+}
+
+impl std::error::Error for EmitOverflow {}
+
+/// The id of the tokio task currently executing, if available.
+///
+/// `tokio::task::id()` panics outside of a task context and is only built
+/// with `tokio_unstable` (mirroring upstream tokio's own task-id tests), so
+/// this wraps it in a `cfg`-gated, panic-safe helper that degrades to `None`
+/// on builds without the unstable feature.
+#[cfg(tokio_unstable)]
+fn current_task_id() -> Option<u64> {
+    // `Id`'s `Display` impl is the only stable way to get at the inner
+    // number; there's no public `as_u64`.
+    tokio::task::try_id().map(|id| {
+        id.to_string()
+            .parse()
+            .expect("tokio::task::Id always formats as an integer")
+    })
+}
+
+#[cfg(not(tokio_unstable))]
+fn current_task_id() -> Option<u64> {
+    None
+}
+
+/// The id of the current tracing span, if any, used as a lightweight
+/// correlation id for events emitted from within a sub-agent's span.
+fn current_span_id() -> Option<String> {
+    tracing::Span::current()
+        .id()
+        .map(|id| id.into_u64().to_string())
+}
+
+/// Commands sent over the bounded *data lane*.
+///
+/// Only `EmitEvent` travels here, since it's the only command a runaway
+/// agent can flood the coordinator with (e.g. `TextDelta`/`Reasoning`
+/// streaming faster than `runtime.emit` drains it).
 #[derive(Debug)]
-pub enum CoordinatorCommand {
+enum DataCommand {
     /// Emit an AI event to the frontend.
     /// Boxed to reduce variant size disparity (AiEvent is large).
-    EmitEvent { event: Box<AiEvent> },
+    EmitEvent {
+        event: Box<AiEvent>,
+        /// Id of the tokio task that called `emit`/`try_emit`/`emit_async`,
+        /// captured at the call site so concurrent sub-agent tasks can be
+        /// told apart once their events interleave in the coordinator.
+        origin_task_id: Option<u64>,
+        /// Id of the tracing span active at the call site, for correlating
+        /// an event with the rest of that span's log output.
+        parent_span_id: Option<String>,
+    },
+}
 
+/// Commands sent over the always-deliverable *control lane*.
+///
+/// These are rare relative to event emission and must never block behind a
+/// backlog of data-lane sends, so the control lane stays unbounded.
+#[derive(Debug)]
+enum ControlCommand {
     /// Mark the frontend as ready to receive events (flushes buffer).
     MarkFrontendReady,
 
@@ -72,6 +214,13 @@ pub enum CoordinatorCommand {
     RegisterApproval {
         request_id: String,
         response_tx: oneshot::Sender<ApprovalDecision>,
+        /// Id of the tokio task that owns this approval request, so
+        /// `CoordinatorState::task_activity` can report it against that
+        /// task.
+        origin_task_id: Option<u64>,
+        /// Timeout for this specific approval. Falls back to
+        /// `EventCoordinatorConfig::default_approval_timeout` if `None`.
+        timeout: Option<Duration>,
     },
 
     /// Resolve a pending approval with a decision.
@@ -82,10 +231,24 @@ pub enum CoordinatorCommand {
         response_tx: oneshot::Sender<CoordinatorState>,
     },
 
+    /// Replay every retained envelope with `seq > after_seq`, for a
+    /// reconnecting frontend resynchronizing from its last-seen sequence.
+    ReplaySince {
+        after_seq: u64,
+        response_tx: oneshot::Sender<ReplayResult>,
+    },
+
     /// Shutdown the coordinator.
     Shutdown,
 }
 
+/// A command pulled off either lane, tagged by which one it came from -
+/// used only inside `EventCoordinator::run`'s select loop.
+enum Command {
+    Data(DataCommand),
+    Control(ControlCommand),
+}
+
 /// Snapshot of coordinator state for debugging/testing.
 #[derive(Debug, Clone)]
 pub struct CoordinatorState {
@@ -99,43 +262,135 @@ pub struct CoordinatorState {
     pub pending_approval_count: usize,
     /// List of pending approval request IDs.
     pub pending_approval_ids: Vec<String>,
+    /// Remaining time-to-live for each pending approval, keyed by request
+    /// id. `None` means that approval has no timeout and will wait
+    /// indefinitely for a decision.
+    pub pending_approval_ttls: HashMap<String, Option<Duration>>,
+    /// Per-task-id event and pending-approval counts, for attributing
+    /// coordinator activity to the concurrent (often sub-agent) task that
+    /// produced it. Events/approvals with no captured task id (e.g. builds
+    /// without `tokio_unstable`) aren't represented here.
+    pub task_activity: HashMap<u64, TaskActivity>,
+}
+
+/// Aggregate coordinator activity owned by a single tokio task id.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TaskActivity {
+    /// Number of events emitted by this task since the coordinator started.
+    pub event_count: u64,
+    /// Number of approvals currently pending that this task registered.
+    pub pending_approval_count: usize,
+}
+
+/// Result of [`CoordinatorHandle::replay_since`].
+#[derive(Debug, Clone)]
+pub struct ReplayResult {
+    /// Every retained envelope with `seq > after_seq`, in sequence order.
+    pub envelopes: Vec<AiEventEnvelope>,
+    /// `true` if `after_seq` is older than the oldest envelope still in the
+    /// replay buffer - i.e. some events between `after_seq` and the first
+    /// returned envelope were already evicted and can't be replayed. A
+    /// reconnecting client seeing this should treat its local state as
+    /// stale (e.g. refetch the transcript) rather than assume `envelopes`
+    /// is a gapless continuation.
+    pub evicted: bool,
 }
 
 /// Handle for sending commands to the EventCoordinator.
 ///
-/// This handle is cheap to clone and can be passed around freely.
-/// Commands are sent via an unbounded channel for fire-and-forget semantics.
+/// This handle is cheap to clone and can be passed around freely. Event
+/// emission travels over a bounded *data lane* that applies backpressure
+/// (or drops events, depending on how they're sent); control commands
+/// (`mark_frontend_ready`, `register_approval`, `resolve_approval`,
+/// `query_state`, `shutdown`) travel over an unbounded *control lane* so
+/// they're never stuck behind a backlog of stream deltas.
 #[derive(Clone)]
 pub struct CoordinatorHandle {
-    tx: mpsc::UnboundedSender<CoordinatorCommand>,
+    data_tx: mpsc::Sender<DataCommand>,
+    control_tx: mpsc::UnboundedSender<ControlCommand>,
 }
 
 impl CoordinatorHandle {
     /// Emit an AI event (fire-and-forget).
     ///
-    /// If the frontend is not ready, the event will be buffered.
+    /// If the frontend is not ready, the event will be buffered. If the data
+    /// lane is full, this event is silently dropped (`OverflowPolicy` does
+    /// not apply to the data lane) - use [`Self::try_emit`] to find out when that happens, or
+    /// [`Self::emit_async`] to wait for room instead of dropping.
     pub fn emit(&self, event: AiEvent) {
-        let _ = self.tx.send(CoordinatorCommand::EmitEvent {
-            event: Box::new(event),
-        });
+        let _ = self.try_emit(event);
+    }
+
+    /// Emit an AI event without blocking.
+    ///
+    /// Returns `Err(EmitOverflow)` if the data lane is full and the event
+    /// was dropped instead of queued.
+    pub fn try_emit(&self, event: AiEvent) -> Result<(), EmitOverflow> {
+        self.data_tx
+            .try_send(DataCommand::EmitEvent {
+                event: Box::new(event),
+                origin_task_id: current_task_id(),
+                parent_span_id: current_span_id(),
+            })
+            .map_err(|_| EmitOverflow)
+    }
+
+    /// Emit an AI event, waiting for room in the data lane if it's full.
+    ///
+    /// This is the backpressure path: a producer that calls this instead of
+    /// `emit`/`try_emit` will slow down to match the coordinator's drain
+    /// rate rather than dropping events.
+    pub async fn emit_async(&self, event: AiEvent) {
+        let _ = self
+            .data_tx
+            .send(DataCommand::EmitEvent {
+                event: Box::new(event),
+                origin_task_id: current_task_id(),
+                parent_span_id: current_span_id(),
+            })
+            .await;
     }
 
     /// Mark the frontend as ready to receive events.
     ///
     /// This flushes any buffered events in sequence order.
     pub fn mark_frontend_ready(&self) {
-        let _ = self.tx.send(CoordinatorCommand::MarkFrontendReady);
+        let _ = self.control_tx.send(ControlCommand::MarkFrontendReady);
     }
 
     /// Register a pending approval request.
     ///
     /// Returns a receiver that will receive the approval decision
-    /// when `resolve_approval` is called with a matching request ID.
+    /// when `resolve_approval` is called with a matching request ID, or
+    /// when the request times out (per
+    /// `EventCoordinatorConfig::default_approval_timeout`) and a
+    /// synthesized decision is sent instead.
     pub fn register_approval(&self, request_id: String) -> oneshot::Receiver<ApprovalDecision> {
+        self.register_approval_with_timeout_impl(request_id, None)
+    }
+
+    /// Register a pending approval request with an explicit timeout,
+    /// overriding `EventCoordinatorConfig::default_approval_timeout` for
+    /// this one request.
+    pub fn register_approval_with_timeout(
+        &self,
+        request_id: String,
+        timeout: Duration,
+    ) -> oneshot::Receiver<ApprovalDecision> {
+        self.register_approval_with_timeout_impl(request_id, Some(timeout))
+    }
+
+    fn register_approval_with_timeout_impl(
+        &self,
+        request_id: String,
+        timeout: Option<Duration>,
+    ) -> oneshot::Receiver<ApprovalDecision> {
         let (response_tx, response_rx) = oneshot::channel();
-        let _ = self.tx.send(CoordinatorCommand::RegisterApproval {
+        let _ = self.control_tx.send(ControlCommand::RegisterApproval {
             request_id,
             response_tx,
+            origin_task_id: current_task_id(),
+            timeout,
         });
         response_rx
     }
@@ -144,7 +399,9 @@ impl CoordinatorHandle {
     ///
     /// The decision will be sent to the receiver registered with `register_approval`.
     pub fn resolve_approval(&self, decision: ApprovalDecision) {
-        let _ = self.tx.send(CoordinatorCommand::ResolveApproval { decision });
+        let _ = self
+            .control_tx
+            .send(ControlCommand::ResolveApproval { decision });
     }
 
     /// Query the current coordinator state.
@@ -153,8 +410,29 @@ impl CoordinatorHandle {
     pub async fn query_state(&self) -> Option<CoordinatorState> {
         let (response_tx, response_rx) = oneshot::channel();
         if self
-            .tx
-            .send(CoordinatorCommand::QueryState { response_tx })
+            .control_tx
+            .send(ControlCommand::QueryState { response_tx })
+            .is_err()
+        {
+            return None;
+        }
+        response_rx.await.ok()
+    }
+
+    /// Replay every retained envelope with `seq > after_seq`, in order.
+    ///
+    /// For a frontend that disconnects and reconnects mid-turn: pass the
+    /// sequence number of the last envelope it saw to resynchronize just
+    /// the tail it missed, instead of replaying (or losing) the whole
+    /// stream. Returns `None` if the coordinator has shut down.
+    pub async fn replay_since(&self, after_seq: u64) -> Option<ReplayResult> {
+        let (response_tx, response_rx) = oneshot::channel();
+        if self
+            .control_tx
+            .send(ControlCommand::ReplaySince {
+                after_seq,
+                response_tx,
+            })
             .is_err()
         {
             return None;
@@ -164,12 +442,81 @@ impl CoordinatorHandle {
 
     /// Shutdown the coordinator.
     pub fn shutdown(&self) {
-        let _ = self.tx.send(CoordinatorCommand::Shutdown);
+        let _ = self.control_tx.send(ControlCommand::Shutdown);
     }
 
     /// Check if the coordinator is still running.
     pub fn is_alive(&self) -> bool {
-        !self.tx.is_closed()
+        !self.control_tx.is_closed()
+    }
+}
+
+/// Configuration for spawning an [`EventCoordinator`].
+///
+/// `Default` matches the behavior of the plain [`EventCoordinator::spawn`]
+/// convenience constructor.
+#[derive(Debug, Clone, Copy)]
+pub struct EventCoordinatorConfig {
+    /// Capacity of the bounded data lane carrying `EmitEvent` commands.
+    pub data_lane_capacity: usize,
+    /// Cap on how many events can accumulate in `event_buffer` before
+    /// `frontend_ready` is signaled.
+    pub event_buffer_cap: usize,
+    /// What to do when the event buffer is full. The data lane does not use
+    /// it: a full data lane always drops the new event.
+    pub overflow_policy: OverflowPolicy,
+    /// If set, `TextDelta`/`Reasoning` events are coalesced into a
+    /// per-turn accumulator instead of being emitted immediately, and
+    /// flushed as a single merged envelope at most once per interval.
+    /// `None` (the default) preserves the original one-envelope-per-event
+    /// behavior.
+    pub coalesce_interval: Option<Duration>,
+    /// How many of the most recent emitted envelopes are retained for
+    /// [`CoordinatorHandle::replay_since`]. Older envelopes are evicted
+    /// first.
+    pub replay_buffer_cap: usize,
+    /// Default timeout applied to approvals registered via
+    /// [`CoordinatorHandle::register_approval`] (not
+    /// `register_approval_with_timeout`, which always overrides this).
+    /// `None` (the default) means approvals wait indefinitely unless a
+    /// per-request timeout is given.
+    pub default_approval_timeout: Option<Duration>,
+}
+
+impl Default for EventCoordinatorConfig {
+    fn default() -> Self {
+        Self {
+            data_lane_capacity: DEFAULT_DATA_LANE_CAPACITY,
+            event_buffer_cap: DEFAULT_EVENT_BUFFER_CAP,
+            overflow_policy: OverflowPolicy::default(),
+            coalesce_interval: None,
+            replay_buffer_cap: DEFAULT_REPLAY_BUFFER_CAP,
+            default_approval_timeout: None,
+        }
+    }
+}
+
+/// A `TextDelta` or `Reasoning` stream's accumulated-but-not-yet-emitted
+/// text for one turn, used while coalescing is active.
+#[derive(Debug, Default)]
+struct PendingCoalesced {
+    /// Concatenation of `TextDelta::delta` chunks since the last flush.
+    text_delta: String,
+    /// The most recent `TextDelta::accumulated` value seen since the last
+    /// flush (already cumulative, so only the latest one matters).
+    text_accumulated: String,
+    /// Whether any `TextDelta` has been accumulated since the last flush.
+    has_text_delta: bool,
+    /// Concatenation of `Reasoning::content` chunks since the last flush
+    /// (each chunk is itself incremental, unlike `TextDelta::accumulated`).
+    reasoning: String,
+    /// Whether any `Reasoning` has been accumulated since the last flush.
+    has_reasoning: bool,
+}
+
+impl PendingCoalesced {
+    fn is_empty(&self) -> bool {
+        !self.has_text_delta && !self.has_reasoning
     }
 }
 
@@ -180,20 +527,58 @@ pub struct EventCoordinator {
     event_sequence: u64,
     /// Whether the frontend has signaled it is ready to receive events.
     frontend_ready: bool,
-    /// Buffer for events emitted before frontend signals ready.
-    event_buffer: Vec<AiEventEnvelope>,
-    /// Pending approval requests waiting for decisions.
-    pending_approvals: HashMap<String, oneshot::Sender<ApprovalDecision>>,
+    /// Buffer for events emitted before frontend signals ready. A
+    /// `VecDeque` so `OverflowPolicy::DropOldest` can evict from the front.
+    event_buffer: VecDeque<AiEventEnvelope>,
+    /// Cap on `event_buffer`'s size (see `EventCoordinatorConfig`).
+    event_buffer_cap: usize,
+    /// Policy applied when `event_buffer` is at `event_buffer_cap`.
+    overflow_policy: OverflowPolicy,
+    /// Pending approval requests waiting for decisions, alongside the task
+    /// id that registered each one (if captured).
+    pending_approvals: HashMap<String, PendingApproval>,
     /// Session ID for event routing.
     session_id: String,
     /// Runtime for emitting events.
     runtime: Arc<dyn QbitRuntime>,
     /// Transcript writer for persisting events (optional).
     transcript_writer: Option<Arc<TranscriptWriter>>,
+    /// Flush interval for `TextDelta`/`Reasoning` coalescing. `None`
+    /// disables coalescing entirely.
+    coalesce_interval: Option<Duration>,
+    /// `turn_id` of the most recent `AiEvent::Started`, used to key
+    /// `pending_coalesced`. Streaming events that arrive before any
+    /// `Started` (shouldn't normally happen) fall back to an empty key.
+    current_turn_id: String,
+    /// Per-turn accumulators for coalesced `TextDelta`/`Reasoning` events,
+    /// keyed by `current_turn_id` at the time each chunk arrived.
+    pending_coalesced: HashMap<String, PendingCoalesced>,
+    /// Number of events emitted so far by each originating task id, for
+    /// `CoordinatorState::task_activity`.
+    task_event_counts: HashMap<u64, u64>,
+    /// Ring buffer of the most recently created envelopes, for
+    /// `replay_since`. Populated independent of `frontend_ready`/buffering,
+    /// since replay is keyed by sequence number, not delivery state.
+    replay_buffer: VecDeque<AiEventEnvelope>,
+    /// Cap on `replay_buffer`'s size (see `EventCoordinatorConfig`).
+    replay_buffer_cap: usize,
+    /// Default timeout applied to approvals registered without an explicit
+    /// one (see `EventCoordinatorConfig::default_approval_timeout`).
+    default_approval_timeout: Option<Duration>,
+}
+
+/// A pending approval request, tagged with the task that registered it so
+/// `CoordinatorState::task_activity` can attribute it correctly, and with
+/// an optional deadline after which it's auto-resolved.
+struct PendingApproval {
+    response_tx: oneshot::Sender<ApprovalDecision>,
+    origin_task_id: Option<u64>,
+    deadline: Option<tokio::time::Instant>,
 }
 
 impl EventCoordinator {
-    /// Spawn a new EventCoordinator task.
+    /// Spawn a new EventCoordinator task with the default data lane capacity,
+    /// event buffer cap, and overflow policy.
     ///
     /// Returns a handle for sending commands to the coordinator.
     pub fn spawn(
@@ -201,62 +586,192 @@ impl EventCoordinator {
         runtime: Arc<dyn QbitRuntime>,
         transcript_writer: Option<Arc<TranscriptWriter>>,
     ) -> CoordinatorHandle {
-        let (tx, rx) = mpsc::unbounded_channel();
+        Self::spawn_with_config(
+            session_id,
+            runtime,
+            transcript_writer,
+            EventCoordinatorConfig::default(),
+        )
+    }
+
+    /// Spawn a new EventCoordinator task with an explicit
+    /// [`EventCoordinatorConfig`], controlling the data lane's capacity, the
+    /// event buffer's cap, and the overflow policy applied to the event
+    /// buffer.
+    pub fn spawn_with_config(
+        session_id: String,
+        runtime: Arc<dyn QbitRuntime>,
+        transcript_writer: Option<Arc<TranscriptWriter>>,
+        config: EventCoordinatorConfig,
+    ) -> CoordinatorHandle {
+        let (data_tx, data_rx) = mpsc::channel(config.data_lane_capacity.max(1));
+        let (control_tx, control_rx) = mpsc::unbounded_channel();
 
         let coordinator = Self {
             event_sequence: 0,
             frontend_ready: false,
-            event_buffer: Vec::new(),
+            event_buffer: VecDeque::new(),
+            event_buffer_cap: config.event_buffer_cap.max(1),
+            overflow_policy: config.overflow_policy,
             pending_approvals: HashMap::new(),
             session_id,
             runtime,
             transcript_writer,
+            coalesce_interval: config.coalesce_interval,
+            current_turn_id: String::new(),
+            pending_coalesced: HashMap::new(),
+            task_event_counts: HashMap::new(),
+            replay_buffer: VecDeque::new(),
+            replay_buffer_cap: config.replay_buffer_cap.max(1),
+            default_approval_timeout: config.default_approval_timeout,
         };
 
-        tokio::spawn(coordinator.run(rx));
+        tokio::spawn(coordinator.run(data_rx, control_rx));
 
-        CoordinatorHandle { tx }
+        CoordinatorHandle {
+            data_tx,
+            control_tx,
+        }
     }
 
     /// Run the coordinator event loop.
-    async fn run(mut self, mut rx: mpsc::UnboundedReceiver<CoordinatorCommand>) {
+    ///
+    /// The control lane is polled with priority over the data lane (a
+    /// `biased` select), so `MarkFrontendReady`/`ResolveApproval`/
+    /// `QueryState`/`Shutdown` are never stuck behind a backlog of
+    /// `EmitEvent` commands.
+    async fn run(
+        mut self,
+        mut data_rx: mpsc::Receiver<DataCommand>,
+        mut control_rx: mpsc::UnboundedReceiver<ControlCommand>,
+    ) {
         tracing::debug!(
             session_id = %self.session_id,
             "EventCoordinator started"
         );
 
-        while let Some(command) = rx.recv().await {
+        // Only ticks when coalescing is enabled; the `if let` guard on the
+        // select arm below makes this branch inert otherwise.
+        let mut coalesce_tick = self.coalesce_interval.map(tokio::time::interval);
+
+        loop {
+            let command = tokio::select! {
+                biased;
+
+                control = control_rx.recv() => match control {
+                    Some(command) => Command::Control(command),
+                    None => {
+                        // Control lane closed (all handles dropped) but the
+                        // data lane might still have buffered sends; keep
+                        // draining it until it closes too. `CoordinatorHandle`
+                        // drops both lanes together, so `data_rx` may already
+                        // be closed here too — `recv()` on a closed channel
+                        // still yields any buffered items before returning
+                        // `None`, so this reliably drains to exhaustion
+                        // rather than discarding a final burst of events.
+                        match data_rx.recv().await {
+                            Some(command) => Command::Data(command),
+                            None => break,
+                        }
+                    }
+                },
+                _ = async { coalesce_tick.as_mut().unwrap().tick().await }, if coalesce_tick.is_some() => {
+                    self.flush_all_coalesced();
+                    continue;
+                },
+                // Wakes at the soonest pending approval's deadline; inert
+                // (via the guard) whenever nothing has a deadline, since
+                // `next_approval_deadline` is recomputed fresh each time
+                // the loop goes back around.
+                _ = async { tokio::time::sleep_until(self.next_approval_deadline().unwrap()).await },
+                    if self.next_approval_deadline().is_some() => {
+                    self.expire_timed_out_approvals().await;
+                    continue;
+                },
+                data = data_rx.recv() => match data {
+                    Some(command) => Command::Data(command),
+                    None => continue,
+                },
+            };
+
             match command {
-                CoordinatorCommand::EmitEvent { event } => {
-                    self.handle_emit_event(*event).await;
+                Command::Data(DataCommand::EmitEvent {
+                    event,
+                    origin_task_id,
+                    parent_span_id,
+                }) => {
+                    self.handle_emit_event(*event, origin_task_id, parent_span_id)
+                        .await;
                 }
-                CoordinatorCommand::MarkFrontendReady => {
+                Command::Control(ControlCommand::MarkFrontendReady) => {
                     self.handle_mark_frontend_ready().await;
                 }
-                CoordinatorCommand::RegisterApproval {
+                Command::Control(ControlCommand::RegisterApproval {
                     request_id,
                     response_tx,
-                } => {
-                    self.handle_register_approval(request_id, response_tx);
+                    origin_task_id,
+                    timeout,
+                }) => {
+                    self.handle_register_approval(request_id, response_tx, origin_task_id, timeout);
                 }
-                CoordinatorCommand::ResolveApproval { decision } => {
+                Command::Control(ControlCommand::ResolveApproval { decision }) => {
                     self.handle_resolve_approval(decision);
                 }
-                CoordinatorCommand::QueryState { response_tx } => {
+                Command::Control(ControlCommand::QueryState { response_tx }) => {
+                    let mut task_activity: HashMap<u64, TaskActivity> = self
+                        .task_event_counts
+                        .iter()
+                        .map(|(&task_id, &event_count)| {
+                            (
+                                task_id,
+                                TaskActivity {
+                                    event_count,
+                                    pending_approval_count: 0,
+                                },
+                            )
+                        })
+                        .collect();
+                    for approval in self.pending_approvals.values() {
+                        if let Some(task_id) = approval.origin_task_id {
+                            task_activity.entry(task_id).or_default().pending_approval_count += 1;
+                        }
+                    }
+
+                    let now = tokio::time::Instant::now();
+                    let pending_approval_ttls: HashMap<String, Option<Duration>> = self
+                        .pending_approvals
+                        .iter()
+                        .map(|(request_id, approval)| {
+                            let ttl = approval
+                                .deadline
+                                .map(|deadline| deadline.saturating_duration_since(now));
+                            (request_id.clone(), ttl)
+                        })
+                        .collect();
+
                     let state = CoordinatorState {
                         event_sequence: self.event_sequence,
                         frontend_ready: self.frontend_ready,
                         buffered_event_count: self.event_buffer.len(),
                         pending_approval_count: self.pending_approvals.len(),
                         pending_approval_ids: self.pending_approvals.keys().cloned().collect(),
+                        pending_approval_ttls,
+                        task_activity,
                     };
                     let _ = response_tx.send(state);
                 }
-                CoordinatorCommand::Shutdown => {
+                Command::Control(ControlCommand::ReplaySince {
+                    after_seq,
+                    response_tx,
+                }) => {
+                    let _ = response_tx.send(self.replay_since(after_seq));
+                }
+                Command::Control(ControlCommand::Shutdown) => {
                     tracing::debug!(
                         session_id = %self.session_id,
                         "EventCoordinator shutting down"
                     );
+                    self.flush_all_coalesced();
                     break;
                 }
             }
@@ -270,12 +785,83 @@ impl EventCoordinator {
         );
     }
 
+    /// Push `envelope` onto `event_buffer`, applying `overflow_policy` if
+    /// it's already at `event_buffer_cap`.
+    fn buffer_event(&mut self, envelope: AiEventEnvelope) {
+        if self.event_buffer.len() >= self.event_buffer_cap {
+            match self.overflow_policy {
+                OverflowPolicy::DropNewest => {
+                    tracing::warn!(
+                        session_id = %self.session_id,
+                        cap = self.event_buffer_cap,
+                        "Event buffer full, dropping newest event"
+                    );
+                    return;
+                }
+                OverflowPolicy::DropOldest => {
+                    tracing::warn!(
+                        session_id = %self.session_id,
+                        cap = self.event_buffer_cap,
+                        "Event buffer full, dropping oldest event"
+                    );
+                    self.event_buffer.pop_front();
+                }
+            }
+        }
+        self.event_buffer.push_back(envelope);
+    }
+
     /// Create an event envelope with sequence number and timestamp.
-    fn create_envelope(&mut self, event: AiEvent) -> AiEventEnvelope {
+    ///
+    /// `origin_task_id`/`parent_span_id` identify the tokio task and
+    /// tracing span that produced `event` (absent for internally-generated
+    /// envelopes, like a coalesced flush that merges chunks from more than
+    /// one emit call). When `origin_task_id` is present, it's tallied into
+    /// `task_event_counts` for `CoordinatorState::task_activity`.
+    fn create_envelope(
+        &mut self,
+        event: AiEvent,
+        origin_task_id: Option<u64>,
+        parent_span_id: Option<String>,
+    ) -> AiEventEnvelope {
         let seq = self.event_sequence;
         self.event_sequence += 1;
         let ts = chrono::Utc::now().to_rfc3339();
-        AiEventEnvelope { seq, ts, event }
+        if let Some(task_id) = origin_task_id {
+            *self.task_event_counts.entry(task_id).or_insert(0) += 1;
+        }
+        let envelope = AiEventEnvelope {
+            seq,
+            ts,
+            event,
+            origin_task_id,
+            parent_span_id,
+        };
+
+        if self.replay_buffer.len() >= self.replay_buffer_cap {
+            self.replay_buffer.pop_front();
+        }
+        self.replay_buffer.push_back(envelope.clone());
+
+        envelope
+    }
+
+    /// Handle ReplaySince command: collect every retained envelope newer
+    /// than `after_seq`, flagging whether some older ones were evicted.
+    fn replay_since(&self, after_seq: u64) -> ReplayResult {
+        let envelopes: Vec<AiEventEnvelope> = self
+            .replay_buffer
+            .iter()
+            .filter(|envelope| envelope.seq > after_seq)
+            .cloned()
+            .collect();
+
+        let evicted = match self.replay_buffer.front() {
+            Some(oldest) => after_seq + 1 < oldest.seq,
+            None => after_seq + 1 < self.event_sequence,
+        };
+
+        ReplayResult { envelopes, evicted }
     }
 
     /// Check if an event should be written to the transcript.
@@ -319,31 +905,58 @@ impl EventCoordinator {
     }
 
     /// Handle EmitEvent command.
-    async fn handle_emit_event(&mut self, event: AiEvent) {
+    async fn handle_emit_event(
+        &mut self,
+        event: AiEvent,
+        origin_task_id: Option<u64>,
+        parent_span_id: Option<String>,
+    ) {
+        if self.coalesce_interval.is_some() {
+            match &event {
+                AiEvent::TextDelta { delta, accumulated } => {
+                    let entry = self
+                        .pending_coalesced
+                        .entry(self.current_turn_id.clone())
+                        .or_default();
+                    entry.text_delta.push_str(delta);
+                    entry.text_accumulated.clone_from(accumulated);
+                    entry.has_text_delta = true;
+                    return;
+                }
+                AiEvent::Reasoning { content } => {
+                    let entry = self
+                        .pending_coalesced
+                        .entry(self.current_turn_id.clone())
+                        .or_default();
+                    entry.reasoning.push_str(content);
+                    entry.has_reasoning = true;
+                    return;
+                }
+                _ => {
+                    // Non-streaming event: flush whatever's pending for the
+                    // current turn first so ordering and sequence numbers
+                    // stay monotonic.
+                    self.flush_turn(&self.current_turn_id.clone());
+                }
+            }
+        }
+
+        if let AiEvent::Started { turn_id } = &event {
+            self.current_turn_id = turn_id.clone();
+        }
+
         // Write to transcript
         self.write_to_transcript(&event).await;
 
         // Create envelope with sequence number
-        let envelope = self.create_envelope(event);
-
-        // If frontend is not ready, buffer the event
-        if !self.frontend_ready {
-            tracing::debug!(
-                session_id = %self.session_id,
-                seq = envelope.seq,
-                event_type = envelope.event.event_type(),
-                "Buffering event (frontend not ready)"
-            );
-            self.event_buffer.push(envelope);
-            return;
-        }
-
-        // Emit directly
-        self.emit_envelope(envelope);
+        let envelope = self.create_envelope(event, origin_task_id, parent_span_id);
+        self.route_envelope(envelope);
     }
 
     /// Handle MarkFrontendReady command.
     async fn handle_mark_frontend_ready(&mut self) {
+        self.flush_all_coalesced();
+
         let buffered_count = self.event_buffer.len();
 
         tracing::info!(
@@ -362,32 +975,112 @@ impl EventCoordinator {
         }
     }
 
+    /// Send `envelope` onward: buffered if the frontend isn't ready yet,
+    /// emitted directly otherwise. Shared by normal event handling and by
+    /// coalesced flushes, so both paths respect `frontend_ready` the same
+    /// way.
+    fn route_envelope(&mut self, envelope: AiEventEnvelope) {
+        if !self.frontend_ready {
+            tracing::debug!(
+                session_id = %self.session_id,
+                seq = envelope.seq,
+                event_type = envelope.event.event_type(),
+                "Buffering event (frontend not ready)"
+            );
+            self.buffer_event(envelope);
+            return;
+        }
+
+        self.emit_envelope(envelope);
+    }
+
+    /// Flush the coalesced accumulator for a single turn (if any), emitting
+    /// a merged `TextDelta` and/or `Reasoning` envelope.
+    fn flush_turn(&mut self, turn_id: &str) {
+        let Some(pending) = self.pending_coalesced.remove(turn_id) else {
+            return;
+        };
+        if pending.is_empty() {
+            return;
+        }
+
+        // A merged envelope has no single originating task - it may combine
+        // chunks from several - so it carries no origin_task_id/span.
+        if pending.has_text_delta {
+            let envelope = self.create_envelope(
+                AiEvent::TextDelta {
+                    delta: pending.text_delta,
+                    accumulated: pending.text_accumulated,
+                },
+                None,
+                None,
+            );
+            self.route_envelope(envelope);
+        }
+        if pending.has_reasoning {
+            let envelope = self.create_envelope(
+                AiEvent::Reasoning {
+                    content: pending.reasoning,
+                },
+                None,
+                None,
+            );
+            self.route_envelope(envelope);
+        }
+    }
+
+    /// Flush every turn with a pending coalesced accumulator. Used by the
+    /// interval tick and by `Shutdown`/`MarkFrontendReady`, which must force
+    /// a final flush rather than waiting for the next tick.
+    fn flush_all_coalesced(&mut self) {
+        let turn_ids: Vec<String> = self.pending_coalesced.keys().cloned().collect();
+        for turn_id in turn_ids {
+            self.flush_turn(&turn_id);
+        }
+    }
+
     /// Handle RegisterApproval command.
     fn handle_register_approval(
         &mut self,
         request_id: String,
         response_tx: oneshot::Sender<ApprovalDecision>,
+        origin_task_id: Option<u64>,
+        timeout: Option<Duration>,
     ) {
+        let deadline = timeout
+            .or(self.default_approval_timeout)
+            .map(|timeout| tokio::time::Instant::now() + timeout);
+
         tracing::debug!(
             session_id = %self.session_id,
             request_id = %request_id,
+            timeout_secs = deadline.map(|d| d
+                .saturating_duration_since(tokio::time::Instant::now())
+                .as_secs_f64()),
             "Registering approval request"
         );
-        self.pending_approvals.insert(request_id, response_tx);
+        self.pending_approvals.insert(
+            request_id,
+            PendingApproval {
+                response_tx,
+                origin_task_id,
+                deadline,
+            },
+        );
     }
 
     /// Handle ResolveApproval command.
     fn handle_resolve_approval(&mut self, decision: ApprovalDecision) {
         let request_id = &decision.request_id;
 
-        if let Some(sender) = self.pending_approvals.remove(request_id) {
+        if let Some(approval) = self.pending_approvals.remove(request_id) {
             tracing::debug!(
                 session_id = %self.session_id,
                 request_id = %request_id,
                 approved = decision.approved,
                 "Resolving approval request"
             );
-            let _ = sender.send(decision);
+            let _ = approval.response_tx.send(decision);
         } else {
             tracing::warn!(
                 session_id = %self.session_id,
@@ -396,6 +1089,67 @@ impl EventCoordinator {
             );
         }
     }
+
+    /// The soonest deadline among all pending approvals, if any have one.
+    /// Recomputed on demand rather than cached, since approvals are
+    /// registered/resolved/expired one at a time.
+    fn next_approval_deadline(&self) -> Option<tokio::time::Instant> {
+        self.pending_approvals
+            .values()
+            .filter_map(|approval| approval.deadline)
+            .min()
+    }
+
+    /// Auto-resolve every pending approval whose deadline has passed,
+    /// sending a synthesized decision and emitting an `AiEvent` so the
+    /// frontend learns the request lapsed instead of waiting forever.
+    async fn expire_timed_out_approvals(&mut self) {
+        let now = tokio::time::Instant::now();
+        let expired_ids: Vec<String> = self
+            .pending_approvals
+            .iter()
+            .filter(|(_, approval)| approval.deadline.is_some_and(|deadline| deadline <= now))
+            .map(|(request_id, _)| request_id.clone())
+            .collect();
+
+        for request_id in expired_ids {
+            let Some(approval) = self.pending_approvals.remove(&request_id) else {
+                continue;
+            };
+
+            // No human answered in time: fail safe and deny. A headless
+            // runtime has no human to wait on in the first place, so fall
+            // back to however it's configured to handle approvals in
+            // general instead.
+            let approved = !self.runtime.is_interactive() && self.runtime.auto_approve();
+
+            tracing::warn!(
+                session_id = %self.session_id,
+                request_id = %request_id,
+                approved,
+                "Approval request timed out"
+            );
+
+            let decision = ApprovalDecision {
+                request_id: request_id.clone(),
+                approved,
+                reason: Some("timed out".to_string()),
+                remember: false,
+                always_allow: false,
+            };
+            let _ = approval.response_tx.send(decision);
+
+            let envelope = self.create_envelope(
+                AiEvent::ApprovalTimedOut {
+                    request_id,
+                    approved,
+                },
+                approval.origin_task_id,
+                None,
+            );
+            self.route_envelope(envelope);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -575,4 +1329,319 @@ mod tests {
         // After shutdown, query_state should return None
         assert!(handle.query_state().await.is_none());
     }
+
+    #[tokio::test]
+    async fn test_try_emit_returns_overflow_when_data_lane_full() {
+        let runtime = Arc::new(MockRuntime::new());
+        let config = EventCoordinatorConfig {
+            data_lane_capacity: 2,
+            ..Default::default()
+        };
+        let handle =
+            EventCoordinator::spawn_with_config("test-session".to_string(), runtime, None, config);
+
+        // Fill the data lane's two slots before yielding to the executor,
+        // so the coordinator task has no chance to drain it first.
+        assert!(handle
+            .try_emit(AiEvent::Started {
+                turn_id: "1".to_string()
+            })
+            .is_ok());
+        assert!(handle
+            .try_emit(AiEvent::Started {
+                turn_id: "2".to_string()
+            })
+            .is_ok());
+        assert_eq!(
+            handle.try_emit(AiEvent::Started {
+                turn_id: "3".to_string()
+            }),
+            Err(EmitOverflow)
+        );
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_emit_async_waits_for_room_instead_of_dropping() {
+        let runtime = Arc::new(MockRuntime::new());
+        let config = EventCoordinatorConfig {
+            data_lane_capacity: 1,
+            ..Default::default()
+        };
+        let handle =
+            EventCoordinator::spawn_with_config("test-session".to_string(), runtime.clone(), None, config);
+
+        handle.mark_frontend_ready();
+
+        // Even with a data lane capacity of one, emit_async should succeed
+        // repeatedly by waiting for the coordinator to drain each send
+        // rather than dropping.
+        for i in 0..5 {
+            handle
+                .emit_async(AiEvent::Started {
+                    turn_id: i.to_string(),
+                })
+                .await;
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        assert_eq!(runtime.emit_count(), 5);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_event_buffer_drop_oldest_policy_respects_cap() {
+        let runtime = Arc::new(MockRuntime::new());
+        let config = EventCoordinatorConfig {
+            event_buffer_cap: 2,
+            overflow_policy: OverflowPolicy::DropOldest,
+            ..Default::default()
+        };
+        let handle =
+            EventCoordinator::spawn_with_config("test-session".to_string(), runtime, None, config);
+
+        // Frontend never signals ready, so every emit goes into the capped
+        // buffer. With a cap of 2 and drop-oldest, only the last two survive.
+        handle.emit(AiEvent::Started {
+            turn_id: "1".to_string(),
+        });
+        handle.emit(AiEvent::Started {
+            turn_id: "2".to_string(),
+        });
+        handle.emit(AiEvent::Started {
+            turn_id: "3".to_string(),
+        });
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+        let state = handle.query_state().await.unwrap();
+        assert_eq!(state.buffered_event_count, 2);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_coalescing_merges_rapid_text_deltas_into_one_emit() {
+        let runtime = Arc::new(MockRuntime::new());
+        let config = EventCoordinatorConfig {
+            coalesce_interval: Some(Duration::from_millis(20)),
+            ..Default::default()
+        };
+        let handle =
+            EventCoordinator::spawn_with_config("test-session".to_string(), runtime.clone(), None, config);
+
+        handle.mark_frontend_ready();
+        handle.emit(AiEvent::Started {
+            turn_id: "1".to_string(),
+        });
+        tokio::task::yield_now().await;
+
+        let mut accumulated = String::new();
+        for chunk in ["Hel", "lo, ", "world"] {
+            accumulated.push_str(chunk);
+            handle.emit(AiEvent::TextDelta {
+                delta: chunk.to_string(),
+                accumulated: accumulated.clone(),
+            });
+        }
+
+        // Give the coalescing interval time to tick and flush.
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        // One emit for Started, one merged emit for all three TextDeltas.
+        assert_eq!(runtime.emit_count(), 2);
+
+        let state = handle.query_state().await.unwrap();
+        assert_eq!(state.event_sequence, 2);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_coalescing_flushes_before_non_streaming_event() {
+        let runtime = Arc::new(MockRuntime::new());
+        let config = EventCoordinatorConfig {
+            // Long enough that only the forced flush (not the tick) could
+            // account for the merged TextDelta emit below.
+            coalesce_interval: Some(Duration::from_secs(60)),
+            ..Default::default()
+        };
+        let handle =
+            EventCoordinator::spawn_with_config("test-session".to_string(), runtime.clone(), None, config);
+
+        handle.mark_frontend_ready();
+        handle.emit(AiEvent::Started {
+            turn_id: "1".to_string(),
+        });
+        handle.emit(AiEvent::TextDelta {
+            delta: "partial".to_string(),
+            accumulated: "partial".to_string(),
+        });
+        // A non-streaming event must flush the pending TextDelta first.
+        handle.emit(AiEvent::Started {
+            turn_id: "2".to_string(),
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Started(1), merged TextDelta, Started(2).
+        assert_eq!(runtime.emit_count(), 3);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_coalescing_shutdown_forces_final_flush() {
+        let runtime = Arc::new(MockRuntime::new());
+        let config = EventCoordinatorConfig {
+            coalesce_interval: Some(Duration::from_secs(60)),
+            ..Default::default()
+        };
+        let handle =
+            EventCoordinator::spawn_with_config("test-session".to_string(), runtime.clone(), None, config);
+
+        handle.mark_frontend_ready();
+        handle.emit(AiEvent::Started {
+            turn_id: "1".to_string(),
+        });
+        handle.emit(AiEvent::Reasoning {
+            content: "thinking...".to_string(),
+        });
+        // Let the coordinator take both data-lane events first; the control
+        // lane is polled with priority, so a Shutdown already queued would
+        // otherwise be handled before them.
+        tokio::task::yield_now().await;
+
+        handle.shutdown();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Started, plus the forced flush of the pending Reasoning chunk.
+        assert_eq!(runtime.emit_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_task_activity_empty_without_tokio_unstable() {
+        // Task id capture is a no-op unless built with `tokio_unstable`, so
+        // task_activity should stay empty even after emitting events and
+        // registering an approval - the aggregation itself still runs and
+        // reports zero activity rather than panicking or misattributing.
+        let runtime = Arc::new(MockRuntime::new());
+        let handle = EventCoordinator::spawn("test-session".to_string(), runtime, None);
+
+        handle.mark_frontend_ready();
+        handle.emit(AiEvent::Started {
+            turn_id: "1".to_string(),
+        });
+        let _decision_rx = handle.register_approval("request-1".to_string());
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let state = handle.query_state().await.unwrap();
+        assert!(state.task_activity.is_empty());
+        assert_eq!(state.pending_approval_count, 1);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_replay_since_returns_envelopes_after_given_sequence() {
+        let runtime = Arc::new(MockRuntime::new());
+        let handle = EventCoordinator::spawn("test-session".to_string(), runtime, None);
+
+        handle.mark_frontend_ready();
+        for i in 0..5 {
+            handle.emit(AiEvent::Started {
+                turn_id: i.to_string(),
+            });
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let replay = handle.replay_since(2).await.unwrap();
+        assert_eq!(replay.envelopes.len(), 2);
+        assert_eq!(replay.envelopes[0].seq, 3);
+        assert_eq!(replay.envelopes[1].seq, 4);
+        assert!(!replay.evicted);
+
+        // Nothing newer than the last sequence emitted.
+        let replay = handle.replay_since(4).await.unwrap();
+        assert!(replay.envelopes.is_empty());
+        assert!(!replay.evicted);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_replay_since_flags_eviction_past_buffer_cap() {
+        let runtime = Arc::new(MockRuntime::new());
+        let config = EventCoordinatorConfig {
+            replay_buffer_cap: 2,
+            ..Default::default()
+        };
+        let handle =
+            EventCoordinator::spawn_with_config("test-session".to_string(), runtime, None, config);
+
+        handle.mark_frontend_ready();
+        for i in 0..5 {
+            handle.emit(AiEvent::Started {
+                turn_id: i.to_string(),
+            });
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Only the last two (seq 3, 4) survive a cap of 2; asking for
+        // everything after seq 0 means events 1-2 were already evicted.
+        let replay = handle.replay_since(0).await.unwrap();
+        assert_eq!(replay.envelopes.len(), 2);
+        assert_eq!(replay.envelopes[0].seq, 3);
+        assert!(replay.evicted);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_approval_times_out_with_synthesized_decision() {
+        let runtime = Arc::new(MockRuntime::new());
+        let handle = EventCoordinator::spawn("test-session".to_string(), runtime.clone(), None);
+
+        handle.mark_frontend_ready();
+        let decision_rx =
+            handle.register_approval_with_timeout("request-timeout".to_string(), Duration::from_millis(20));
+
+        // MockRuntime is non-interactive with auto_approve() true, so the
+        // synthesized default decision should approve.
+        let decision = decision_rx.await.unwrap();
+        assert!(decision.approved);
+        assert_eq!(decision.reason, Some("timed out".to_string()));
+        assert_eq!(decision.request_id, "request-timeout");
+
+        let state = handle.query_state().await.unwrap();
+        assert_eq!(state.pending_approval_count, 0);
+
+        // One emit for the timeout's AiEvent::ApprovalTimedOut.
+        assert_eq!(runtime.emit_count(), 1);
+
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn test_approval_ttl_reported_in_coordinator_state() {
+        let runtime = Arc::new(MockRuntime::new());
+        let handle = EventCoordinator::spawn("test-session".to_string(), runtime, None);
+
+        let _decision_rx =
+            handle.register_approval_with_timeout("request-ttl".to_string(), Duration::from_secs(60));
+        let _no_timeout_rx = handle.register_approval("request-no-timeout".to_string());
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let state = handle.query_state().await.unwrap();
+        assert_eq!(state.pending_approval_count, 2);
+        let ttl = state.pending_approval_ttls["request-ttl"].expect("should have a ttl");
+        assert!(ttl <= Duration::from_secs(60) && ttl > Duration::from_secs(30));
+        assert_eq!(state.pending_approval_ttls["request-no-timeout"], None);
+
+        handle.shutdown();
+    }
 }
@@ -28,6 +28,7 @@
 //! - **ApprovalRecorder**: HITL approval tracking and auto-approval
 //! - **LoopDetector**: Detects and prevents infinite agent loops
 //! - **WorkflowRunner**: Executes multi-step graph-based workflows
+//! - **PromptStyle**: Selects the per-model-family base prompt template
 
 pub mod agent_bridge;
 pub mod agent_mode;
@@ -36,12 +37,14 @@ mod bridge_context;
 mod bridge_hitl;
 mod bridge_policy;
 mod bridge_session;
+pub mod codex_prompt;
 pub mod context_manager;
 pub mod context_pruner;
 pub mod hitl;
 pub mod llm_client;
 pub mod loop_detection;
 pub mod memory_file;
+pub mod prompt_style;
 pub mod session;
 pub mod sub_agent;
 pub mod sub_agent_executor;
@@ -65,6 +68,7 @@ pub use hitl::{ApprovalRecorder, ApprovalRequest};
 pub use loop_detection::{
     LoopDetectionResult, LoopDetector, LoopDetectorStats, LoopProtectionConfig,
 };
+pub use prompt_style::{build_prompt, select_prompt_style, PromptStyle};
 pub use session::{QbitMessageRole, QbitSessionMessage, QbitSessionSnapshot, SessionListingInfo};
 pub use sub_agent::{SubAgentContext, SubAgentDefinition, SubAgentRegistry, SubAgentResult};
 pub use token_budget::{
@@ -0,0 +1,137 @@
+//! Model-family prompt style registry.
+//!
+//! Prompt selection used to be an implicit fork: callers had to know to call
+//! [`build_codex_style_prompt`](crate::codex_prompt::build_codex_style_prompt)
+//! for OpenAI models and [`build_system_prompt`](crate::system_prompt::build_system_prompt)
+//! for everything else. [`PromptStyle`] makes that choice an explicit, testable
+//! value; [`select_prompt_style`] maps a model id to one, and [`build_prompt`]
+//! is the single entry point callers need regardless of style.
+//!
+//! Adding a new family (e.g. a future Anthropic- or Gemini-specific base
+//! template) means adding a variant here and a branch in both functions -
+//! callers don't change.
+
+use std::path::Path;
+
+use super::agent_mode::AgentMode;
+use super::codex_prompt::build_codex_style_prompt;
+use super::system_prompt::build_system_prompt;
+
+/// Which base prompt template to use for a given model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PromptStyle {
+    /// The default Qbit prompt: XML-ish structure, explicit phase gates.
+    Default,
+    /// Codex-style prompt for OpenAI models (o-series and other reasoning
+    /// models respond better to less rigid, more natural-language guidance).
+    Codex,
+}
+
+/// Map a provider/model identifier to the [`PromptStyle`] it should use.
+///
+/// Matching is substring-based and case-insensitive so callers can pass
+/// either a bare model name (`"gpt-5-codex"`) or a provider-qualified one
+/// (`"openai/o3-mini"`). Anything unrecognized falls back to
+/// [`PromptStyle::Default`].
+pub fn select_prompt_style(model_id: &str) -> PromptStyle {
+    let model_id = model_id.to_lowercase();
+
+    let is_openai_reasoning_family = model_id.contains("openai")
+        || model_id.contains("gpt-")
+        || model_id.contains("codex")
+        || model_id.starts_with('o')
+            && model_id
+                .chars()
+                .nth(1)
+                .is_some_and(|c| c.is_ascii_digit());
+
+    if is_openai_reasoning_family {
+        PromptStyle::Codex
+    } else {
+        PromptStyle::Default
+    }
+}
+
+/// Build the system prompt for `style`, dispatching to the matching
+/// builder. This is the only entry point callers need - they no longer have
+/// to know which underlying builder corresponds to a given model family.
+///
+/// # Arguments
+/// * `style` - The prompt style to build, from [`select_prompt_style`]
+/// * `workspace_path` - The current workspace directory
+/// * `agent_mode` - The current agent mode (affects available operations)
+/// * `memory_file_path` - Optional path to a memory file (from codebase settings)
+///
+/// # Returns
+/// The complete system prompt string
+pub fn build_prompt(
+    style: PromptStyle,
+    workspace_path: &Path,
+    agent_mode: AgentMode,
+    memory_file_path: Option<&Path>,
+) -> String {
+    match style {
+        PromptStyle::Default => build_system_prompt(workspace_path, agent_mode, memory_file_path),
+        PromptStyle::Codex => {
+            build_codex_style_prompt(workspace_path, agent_mode, memory_file_path)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_select_prompt_style_openai_models() {
+        assert_eq!(select_prompt_style("gpt-4o"), PromptStyle::Codex);
+        assert_eq!(select_prompt_style("o3-mini"), PromptStyle::Codex);
+        assert_eq!(select_prompt_style("openai/gpt-5"), PromptStyle::Codex);
+        assert_eq!(select_prompt_style("gpt-5-codex"), PromptStyle::Codex);
+    }
+
+    #[test]
+    fn test_select_prompt_style_other_models() {
+        assert_eq!(
+            select_prompt_style("claude-sonnet-4"),
+            PromptStyle::Default
+        );
+        assert_eq!(select_prompt_style("gemini-2.0-pro"), PromptStyle::Default);
+        assert_eq!(select_prompt_style("llama-3.1-70b"), PromptStyle::Default);
+    }
+
+    #[test]
+    fn test_build_prompt_dispatches_to_default() {
+        let workspace = PathBuf::from("/tmp/test-workspace");
+        let prompt = build_prompt(PromptStyle::Default, &workspace, AgentMode::Default, None);
+
+        assert!(prompt.contains("You are Qbit"));
+        assert!(prompt.contains("## Core Workflow"));
+    }
+
+    #[test]
+    fn test_build_prompt_dispatches_to_codex() {
+        let workspace = PathBuf::from("/tmp/test-workspace");
+        let prompt = build_prompt(PromptStyle::Codex, &workspace, AgentMode::Default, None);
+
+        assert!(prompt.contains("Core Principles"));
+        assert!(prompt.contains("Sub-Agent Delegation"));
+    }
+
+    #[test]
+    fn test_build_prompt_matches_style_specific_builders() {
+        use super::super::codex_prompt::build_codex_style_prompt;
+        use super::super::system_prompt::build_system_prompt;
+
+        let workspace = PathBuf::from("/tmp/test-workspace");
+        assert_eq!(
+            build_prompt(PromptStyle::Default, &workspace, AgentMode::Default, None),
+            build_system_prompt(&workspace, AgentMode::Default, None)
+        );
+        assert_eq!(
+            build_prompt(PromptStyle::Codex, &workspace, AgentMode::Default, None),
+            build_codex_style_prompt(&workspace, AgentMode::Default, None)
+        );
+    }
+}
@@ -330,7 +330,7 @@ Delegate to researcher for:
 - **NEVER** generate code that logs sensitive data
 
 ## Project Instructions
-{project_instructions}
+{{project_instructions}}
 
 ## Critical Reminders
 1. Read before edit - ALWAYS
@@ -339,15 +339,14 @@ Delegate to researcher for:
 4. Delegate appropriately - DON'T do sub-agent work
 5. Brevity - 4 lines max for responses
 6. Quality gates - Never skip verification
-{agent_mode_instructions}
+{{agent_mode_instructions}}
 "#,
         workspace = workspace_path.display(),
         date = current_date,
-        project_instructions = project_instructions,
         git_repo = git_repo,
         git_branch = git_branch,
-        agent_mode_instructions = agent_mode_instructions
     );
+    prompt = apply_shared_placeholders(&prompt, &project_instructions, &agent_mode_instructions);
 
     // Append dynamic contributions from registered contributors
     if let (Some(registry), Some(ctx)) = (registry, context) {
@@ -365,8 +364,25 @@ Delegate to researcher for:
     prompt
 }
 
+/// Substitute the `{project_instructions}` / `{agent_mode_instructions}`
+/// placeholders shared by every prompt style's base template.
+///
+/// Every style's template (see [`prompt_style`](crate::prompt_style)) embeds
+/// these two placeholders the same way; only the surrounding template text
+/// differs between styles, so this is the one place that substitution logic
+/// lives.
+pub(crate) fn apply_shared_placeholders(
+    template: &str,
+    project_instructions: &str,
+    agent_mode_instructions: &str,
+) -> String {
+    template
+        .replace("{project_instructions}", project_instructions)
+        .replace("{agent_mode_instructions}", agent_mode_instructions)
+}
+
 /// Get agent mode-specific instructions to append to the system prompt.
-fn get_agent_mode_instructions(mode: AgentMode) -> String {
+pub(crate) fn get_agent_mode_instructions(mode: AgentMode) -> String {
     match mode {
         AgentMode::Planning => {
             r#"
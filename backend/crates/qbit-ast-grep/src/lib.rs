@@ -24,20 +24,23 @@
 
 pub mod language;
 pub mod result;
+pub mod rule;
 pub mod tool;
+pub mod walk;
 
 // Re-export tool structs for easy use
 pub use tool::{AstGrepReplaceTool, AstGrepTool};
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use ast_grep_language::{LanguageExt, SupportLang};
-use walkdir::WalkDir;
 
 pub use language::{detect_language, parse_language};
-pub use result::{ReplaceResult, Replacement, SearchMatch, SearchResult};
+pub use result::{FileDiff, ReplaceResult, Replacement, SearchMatch, SearchResult};
+pub use rule::{search_with_rule, replace_with_rule, MetaVarConstraint, Rule, RuleConfig};
+pub use walk::WalkOptions;
 
 /// Search for AST patterns in source code.
 ///
@@ -56,6 +59,28 @@ pub fn search(
     pattern: &str,
     path: Option<&str>,
     language: Option<&str>,
+) -> Result<SearchResult> {
+    search_with_options(workspace, pattern, path, language, &WalkOptions::default(), None)
+}
+
+/// Search for AST patterns in source code, with control over directory
+/// traversal (`.gitignore` handling, include/exclude globs, file-type
+/// filtering - see [`WalkOptions`]) and over how many threads scan matching
+/// files concurrently.
+///
+/// `threads` caps how many worker threads process files in parallel when
+/// searching a directory (`None` uses [`std::thread::available_parallelism`]).
+/// It has no effect when `path` resolves to a single file.
+///
+/// Otherwise identical to [`search`], which is a thin wrapper around this
+/// function using the default walk behaviour and thread count.
+pub fn search_with_options(
+    workspace: &Path,
+    pattern: &str,
+    path: Option<&str>,
+    language: Option<&str>,
+    walk_options: &WalkOptions,
+    threads: Option<usize>,
 ) -> Result<SearchResult> {
     let target_path = match path {
         Some(p) => workspace.join(p),
@@ -63,39 +88,92 @@ pub fn search(
     };
 
     let lang = language.and_then(parse_language);
-    let mut result = SearchResult::new();
 
     if target_path.is_file() {
         // Search single file
+        let mut result = SearchResult::new();
         search_file(&target_path, workspace, pattern, lang, &mut result)?;
         result.files_searched = 1;
+        Ok(result)
     } else if target_path.is_dir() {
-        // Search directory recursively
-        for entry in WalkDir::new(&target_path)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.file_type().is_file())
-        {
-            let file_path = entry.path();
-            // Determine language for this file
-            let file_lang = lang.or_else(|| {
-                file_path
-                    .to_str()
-                    .and_then(detect_language)
-            });
-
-            if file_lang.is_some() {
-                search_file(file_path, workspace, pattern, file_lang, &mut result)?;
-                result.files_searched += 1;
-            }
-        }
+        let files = walk::walk_files(&target_path, walk_options)?;
+        search_files_parallel(&files, workspace, pattern, lang, threads)
     } else {
         anyhow::bail!("Path does not exist: {}", target_path.display());
     }
+}
 
+/// Search `files` across a thread pool, merging each worker's partial
+/// `SearchResult` at the end.
+///
+/// `SearchMatch`es carry their own file path, so the merge is a simple
+/// order-independent concatenation; `files_searched` is accumulated
+/// per-worker and summed at join, so it stays correct under concurrency.
+fn search_files_parallel(
+    files: &[PathBuf],
+    workspace: &Path,
+    pattern: &str,
+    lang: Option<SupportLang>,
+    threads: Option<usize>,
+) -> Result<SearchResult> {
+    let chunks = chunk_files(files, thread_count(threads));
+
+    let partials: Vec<Result<SearchResult>> = std::thread::scope(|scope| {
+        chunks
+            .into_iter()
+            .map(|chunk| {
+                scope.spawn(|| {
+                    let mut partial = SearchResult::new();
+                    for file_path in chunk {
+                        let file_lang =
+                            lang.or_else(|| file_path.to_str().and_then(detect_language));
+
+                        if file_lang.is_some() {
+                            search_file(file_path, workspace, pattern, file_lang, &mut partial)?;
+                            partial.files_searched += 1;
+                        }
+                    }
+                    Ok(partial)
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().expect("search worker thread panicked"))
+            .collect()
+    });
+
+    let mut result = SearchResult::new();
+    for partial in partials {
+        let partial = partial?;
+        result.matches.extend(partial.matches);
+        result.files_searched += partial.files_searched;
+        result.sources.extend(partial.sources);
+    }
     Ok(result)
 }
 
+/// Resolve the requested thread count, defaulting to the available
+/// parallelism (falling back to 1 if it can't be determined).
+fn thread_count(threads: Option<usize>) -> usize {
+    threads
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        })
+        .max(1)
+}
+
+/// Split `files` into up to `n` roughly-even, contiguous chunks for worker
+/// threads to process independently.
+fn chunk_files(files: &[PathBuf], n: usize) -> Vec<&[PathBuf]> {
+    if files.is_empty() {
+        return Vec::new();
+    }
+    let chunk_size = ((files.len() + n - 1) / n).max(1);
+    files.chunks(chunk_size).collect()
+}
+
 /// Search a single file for pattern matches.
 fn search_file(
     file_path: &Path,
@@ -125,8 +203,13 @@ fn search_file(
         .to_string();
 
     // Search the source using ast-grep
+    let matches_before = result.matches.len();
     search_source_impl(&source, pattern, lang, &relative_path, result);
 
+    if result.matches.len() > matches_before {
+        result.sources.insert(relative_path, source);
+    }
+
     Ok(())
 }
 
@@ -186,44 +269,134 @@ pub fn replace(
     replacement: &str,
     path: &str,
     language: Option<&str>,
+) -> Result<ReplaceResult> {
+    replace_with_options(
+        workspace,
+        pattern,
+        replacement,
+        path,
+        language,
+        &WalkOptions::default(),
+        None,
+        false,
+    )
+}
+
+/// Replace AST patterns in source code, with control over directory
+/// traversal (`.gitignore` handling, include/exclude globs, file-type
+/// filtering - see [`WalkOptions`]) and over how many threads rewrite
+/// matching files concurrently.
+///
+/// `threads` caps how many worker threads process files in parallel when
+/// replacing across a directory (`None` uses
+/// [`std::thread::available_parallelism`]); each worker reads, rewrites, and
+/// writes back its own files, so concurrent workers never touch the same
+/// file. It has no effect when `path` resolves to a single file.
+///
+/// When `dry_run` is true, no file is written: `ReplaceResult::diffs` carries
+/// a unified diff per modified file instead, while `files_modified`,
+/// `replacements_count`, and `changes` still reflect what *would* happen.
+/// This lets callers preview and approve a rewrite before it touches disk.
+///
+/// Otherwise identical to [`replace`], which is a thin wrapper around this
+/// function using the default walk behaviour and thread count.
+pub fn replace_with_options(
+    workspace: &Path,
+    pattern: &str,
+    replacement: &str,
+    path: &str,
+    language: Option<&str>,
+    walk_options: &WalkOptions,
+    threads: Option<usize>,
+    dry_run: bool,
 ) -> Result<ReplaceResult> {
     let target_path = workspace.join(path);
     let lang = language.and_then(parse_language);
-    let mut result = ReplaceResult::new();
 
     if target_path.is_file() {
-        replace_file(&target_path, workspace, pattern, replacement, lang, &mut result)?;
+        let mut result = ReplaceResult::new();
+        replace_file(&target_path, workspace, pattern, replacement, lang, dry_run, &mut result)?;
+        Ok(result)
     } else if target_path.is_dir() {
-        for entry in WalkDir::new(&target_path)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.file_type().is_file())
-        {
-            let file_path = entry.path();
-            let file_lang = lang.or_else(|| {
-                file_path
-                    .to_str()
-                    .and_then(detect_language)
-            });
-
-            if file_lang.is_some() {
-                replace_file(file_path, workspace, pattern, replacement, file_lang, &mut result)?;
-            }
-        }
+        let files = walk::walk_files(&target_path, walk_options)?;
+        replace_files_parallel(&files, workspace, pattern, replacement, lang, threads, dry_run)
     } else {
         anyhow::bail!("Path does not exist: {}", target_path.display());
     }
+}
 
+/// Replace patterns across `files` using a thread pool, merging each
+/// worker's partial `ReplaceResult` at the end.
+///
+/// `Replacement`s carry their own file path, so the merge is a simple
+/// order-independent concatenation; `replacements_count` is accumulated
+/// per-worker and summed at join, so it stays correct under concurrency.
+fn replace_files_parallel(
+    files: &[PathBuf],
+    workspace: &Path,
+    pattern: &str,
+    replacement: &str,
+    lang: Option<SupportLang>,
+    threads: Option<usize>,
+    dry_run: bool,
+) -> Result<ReplaceResult> {
+    let chunks = chunk_files(files, thread_count(threads));
+
+    let partials: Vec<Result<ReplaceResult>> = std::thread::scope(|scope| {
+        chunks
+            .into_iter()
+            .map(|chunk| {
+                scope.spawn(|| {
+                    let mut partial = ReplaceResult::new();
+                    for file_path in chunk {
+                        let file_lang =
+                            lang.or_else(|| file_path.to_str().and_then(detect_language));
+
+                        if file_lang.is_some() {
+                            replace_file(
+                                file_path,
+                                workspace,
+                                pattern,
+                                replacement,
+                                file_lang,
+                                dry_run,
+                                &mut partial,
+                            )?;
+                        }
+                    }
+                    Ok(partial)
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().expect("replace worker thread panicked"))
+            .collect()
+    });
+
+    let mut result = ReplaceResult::new();
+    for partial in partials {
+        let partial = partial?;
+        result.files_modified.extend(partial.files_modified);
+        result.replacements_count += partial.replacements_count;
+        result.changes.extend(partial.changes);
+        result.skipped_nested_matches += partial.skipped_nested_matches;
+        result.diffs.extend(partial.diffs);
+    }
     Ok(result)
 }
 
 /// Replace patterns in a single file.
+///
+/// When `dry_run` is true, `new_source` is computed exactly as a normal
+/// replace would, but is never written to disk - instead a unified diff
+/// against the original file is appended to `result.diffs`.
 fn replace_file(
     file_path: &Path,
     workspace: &Path,
     pattern: &str,
     replacement: &str,
     lang: Option<SupportLang>,
+    dry_run: bool,
     result: &mut ReplaceResult,
 ) -> Result<()> {
     let lang = match lang {
@@ -245,11 +418,21 @@ fn replace_file(
         .to_string_lossy()
         .to_string();
 
-    let (new_source, changes) = replace_source_impl(&source, pattern, replacement, lang, &relative_path);
+    let (new_source, changes, skipped_nested) =
+        replace_source_impl(&source, pattern, replacement, lang, &relative_path);
+
+    result.skipped_nested_matches += skipped_nested;
 
     if !changes.is_empty() {
-        fs::write(file_path, &new_source)
-            .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
+        if dry_run {
+            result.diffs.push(FileDiff {
+                file: relative_path.clone(),
+                diff: unified_diff(&relative_path, &source, &new_source),
+            });
+        } else {
+            fs::write(file_path, &new_source)
+                .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
+        }
 
         result.files_modified.push(relative_path);
         result.replacements_count += changes.len();
@@ -259,29 +442,38 @@ fn replace_file(
     Ok(())
 }
 
-/// Replace patterns in source code and return the new source and changes.
+/// Render a unified diff between `original` and `updated`, with a few lines
+/// of context around each hunk, for a dry-run replace's `ReplaceResult::diffs`.
+fn unified_diff(relative_path: &str, original: &str, updated: &str) -> String {
+    let from_path = format!("a/{relative_path}");
+    let to_path = format!("b/{relative_path}");
+    similar::TextDiff::from_lines(original, updated)
+        .unified_diff()
+        .context_radius(3)
+        .header(&from_path, &to_path)
+        .to_string()
+}
+
+/// Replace patterns in source code, returning the new source, the applied
+/// changes, and how many overlapping/nested matches were skipped.
 fn replace_source_impl(
     source: &str,
     pattern: &str,
     replacement: &str,
     lang: SupportLang,
     file_path: &str,
-) -> (String, Vec<Replacement>) {
+) -> (String, Vec<Replacement>, usize) {
     let grep = lang.ast_grep(source);
 
     let mut changes = Vec::new();
     let mut new_source = source.to_string();
 
     // Collect all matches first (we need to apply from end to start to preserve positions)
-    let mut matches: Vec<_> = grep
-        .root()
-        .find_all(pattern)
-        .collect();
+    let matches: Vec<_> = grep.root().find_all(pattern).collect();
 
-    // Sort by position (descending) to apply replacements from end to start
-    matches.sort_by(|a, b| b.range().start.cmp(&a.range().start));
+    let (accepted, skipped_nested) = resolve_overlapping_matches(matches);
 
-    for node_match in matches {
+    for node_match in accepted {
         let original = node_match.text().to_string();
         let start = node_match.start_pos();
         let start_point = start.byte_point();
@@ -304,11 +496,47 @@ fn replace_source_impl(
     // Reverse changes to match file order
     changes.reverse();
 
-    (new_source, changes)
+    (new_source, changes, skipped_nested)
+}
+
+/// Resolve a set of matches down to a non-overlapping subset, descending by
+/// start position so callers can apply replacements end-to-start.
+///
+/// When a pattern matches both an outer node and one nested inside it (or
+/// even the same range twice), `replace_range` on the overlapping ranges
+/// would corrupt the source or panic. Matches are first sorted ascending by
+/// start position; a match is kept only if its start is at or past the end
+/// of the last kept match, otherwise it's discarded as contained/overlapping
+/// (for identical ranges, this keeps whichever copy `find_all` returned
+/// first). Zero-width matches are always discarded - they have nothing to
+/// underline and would never advance the cursor.
+pub(crate) fn resolve_overlapping_matches<D: ast_grep_core::Doc>(
+    mut matches: Vec<ast_grep_core::NodeMatch<D>>,
+) -> (Vec<ast_grep_core::NodeMatch<D>>, usize) {
+    matches.sort_by(|a, b| a.range().start.cmp(&b.range().start));
+
+    let mut accepted = Vec::new();
+    let mut skipped = 0;
+    let mut last_end = 0;
+
+    for node_match in matches {
+        let range = node_match.range();
+        if range.start == range.end || range.start < last_end {
+            skipped += 1;
+            continue;
+        }
+        last_end = range.end;
+        accepted.push(node_match);
+    }
+
+    // Apply replacements from end to start so earlier offsets stay valid.
+    accepted.sort_by(|a, b| b.range().start.cmp(&a.range().start));
+
+    (accepted, skipped)
 }
 
 /// Generate replacement text by substituting captured meta-variables.
-fn generate_replacement<D: ast_grep_core::Doc>(
+pub(crate) fn generate_replacement<D: ast_grep_core::Doc>(
     node_match: &ast_grep_core::NodeMatch<D>,
     replacement: &str,
     _lang: SupportLang,
@@ -376,7 +604,7 @@ pub fn replace_source(
     replacement: &str,
     lang: SupportLang,
 ) -> String {
-    let (new_source, _) = replace_source_impl(source, pattern, replacement, lang, "<source>");
+    let (new_source, _, _) = replace_source_impl(source, pattern, replacement, lang, "<source>");
     new_source
 }
 
@@ -514,6 +742,114 @@ console.log('third');
         assert_eq!(new_content, "logger.info('hello');");
     }
 
+    #[test]
+    fn test_replace_skips_nested_match() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("test.js"),
+            "console.log(console.log('inner'));",
+        )
+        .unwrap();
+
+        let result = replace(
+            tmp.path(),
+            "console.log($MSG)",
+            "logger.info($MSG)",
+            "test.js",
+            Some("javascript"),
+        )
+        .unwrap();
+
+        // The outer call fully contains the inner call, so only the outer
+        // match is applied; the inner one is discarded as nested.
+        assert_eq!(result.replacements_count, 1);
+        assert_eq!(result.skipped_nested_matches, 1);
+
+        let new_content = fs::read_to_string(tmp.path().join("test.js")).unwrap();
+        assert_eq!(new_content, "logger.info(console.log('inner'));");
+    }
+
+    #[test]
+    fn test_directory_search_parallel_matches_serial() {
+        let tmp = TempDir::new().unwrap();
+        for i in 0..10 {
+            fs::write(tmp.path().join(format!("f{i}.rs")), "fn main() {}").unwrap();
+        }
+
+        let result = search_with_options(
+            tmp.path(),
+            "fn $NAME() {}",
+            None,
+            Some("rust"),
+            &WalkOptions::default(),
+            Some(4),
+        )
+        .unwrap();
+
+        assert_eq!(result.matches.len(), 10);
+        assert_eq!(result.files_searched, 10);
+    }
+
+    #[test]
+    fn test_directory_replace_parallel() {
+        let tmp = TempDir::new().unwrap();
+        for i in 0..6 {
+            fs::write(
+                tmp.path().join(format!("f{i}.js")),
+                "console.log('hello');",
+            )
+            .unwrap();
+        }
+
+        let result = replace_with_options(
+            tmp.path(),
+            "console.log($MSG)",
+            "logger.info($MSG)",
+            ".",
+            Some("javascript"),
+            &WalkOptions::default(),
+            Some(3),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(result.files_modified.len(), 6);
+        assert_eq!(result.replacements_count, 6);
+        for i in 0..6 {
+            let content = fs::read_to_string(tmp.path().join(format!("f{i}.js"))).unwrap();
+            assert_eq!(content, "logger.info('hello');");
+        }
+    }
+
+    #[test]
+    fn test_replace_dry_run_leaves_file_untouched_and_produces_diff() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("a.js");
+        fs::write(&file, "console.log('hi');\n").unwrap();
+
+        let result = replace_with_options(
+            tmp.path(),
+            "console.log($MSG)",
+            "logger.info($MSG)",
+            "a.js",
+            Some("javascript"),
+            &WalkOptions::default(),
+            None,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(result.replacements_count, 1);
+        assert_eq!(result.files_modified, vec!["a.js".to_string()]);
+        assert_eq!(result.diffs.len(), 1);
+        assert!(result.diffs[0].diff.contains("-console.log('hi');"));
+        assert!(result.diffs[0].diff.contains("+logger.info('hi');"));
+        assert!(result.diffs[0].diff.contains("@@"));
+
+        // The file itself was never written.
+        assert_eq!(fs::read_to_string(&file).unwrap(), "console.log('hi');\n");
+    }
+
     #[test]
     fn test_search_result_serialization() {
         let result = SearchResult {
@@ -526,6 +862,7 @@ console.log('third');
                 end_column: 9,
             }],
             files_searched: 1,
+            sources: Default::default(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -1,5 +1,7 @@
 //! Result types for AST-grep operations.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// A single match from an AST-grep search.
@@ -26,6 +28,13 @@ pub struct SearchResult {
     pub matches: Vec<SearchMatch>,
     /// Number of files searched.
     pub files_searched: usize,
+    /// Full source of every file a match was found in, keyed by
+    /// [`SearchMatch::file`] - kept around so [`SearchResult::render`] can
+    /// show context lines without re-reading from disk. Not serialized;
+    /// callers that only care about match locations (e.g. the JSON tool
+    /// response) don't pay for it.
+    #[serde(skip)]
+    pub sources: BTreeMap<String, String>,
 }
 
 impl SearchResult {
@@ -34,6 +43,7 @@ impl SearchResult {
         Self {
             matches: Vec::new(),
             files_searched: 0,
+            sources: BTreeMap::new(),
         }
     }
 
@@ -46,6 +56,33 @@ impl SearchResult {
     pub fn is_empty(&self) -> bool {
         self.matches.is_empty()
     }
+
+    /// Render every match as a grep-style snippet with `context_lines` of
+    /// leading/trailing context, the matched span underlined, and (if
+    /// `color` is true) ANSI highlighting.
+    ///
+    /// Returns the concatenated human-readable text alongside the
+    /// structured per-match snippets, so a terminal/PTY layer can either
+    /// print the string directly or re-render the structured form itself.
+    pub fn render(&self, context_lines: usize, color: bool) -> (String, Vec<RenderedSnippet>) {
+        let mut snippets = Vec::with_capacity(self.matches.len());
+        let mut rendered = String::new();
+
+        for (i, m) in self.matches.iter().enumerate() {
+            let source = self.sources.get(&m.file).map(String::as_str).unwrap_or("");
+            let snippet = render_match(source, m, context_lines, color);
+
+            if i > 0 {
+                rendered.push('\n');
+            }
+            rendered.push_str(&snippet.text);
+            rendered.push('\n');
+
+            snippets.push(snippet);
+        }
+
+        (rendered, snippets)
+    }
 }
 
 impl Default for SearchResult {
@@ -54,6 +91,119 @@ impl Default for SearchResult {
     }
 }
 
+/// One rendered context line in a [`RenderedSnippet`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderedLine {
+    /// 1-indexed line number in the source file.
+    pub number: usize,
+    /// The line's text (no trailing newline).
+    pub text: String,
+    /// `Some((start_column, end_column))` (1-indexed, end-exclusive) if this
+    /// line falls within the match span and should be underlined.
+    pub underline: Option<(usize, usize)>,
+}
+
+/// A single match rendered as an annotate-snippets-style diagnostic: a
+/// gutter of line numbers, the match's surrounding context, and a caret
+/// underline beneath the matched span.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderedSnippet {
+    /// File the match was found in.
+    pub file: String,
+    /// Line the match starts on (1-indexed).
+    pub line: usize,
+    /// Column the match starts on (1-indexed).
+    pub column: usize,
+    /// Context and match lines, in file order.
+    pub lines: Vec<RenderedLine>,
+    /// The fully formatted text for just this snippet (gutter, context,
+    /// underline, and ANSI codes if requested).
+    pub text: String,
+}
+
+const ANSI_BOLD_RED: &str = "\x1b[1;31m";
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Render a single match against its file's `source`, producing both the
+/// formatted text and its structured breakdown.
+///
+/// `context_lines` controls how many lines of leading/trailing context
+/// surround the match. Multi-line matches underline every line they span,
+/// from the start column on the first line to the end column on the last.
+pub fn render_match(
+    source: &str,
+    m: &SearchMatch,
+    context_lines: usize,
+    color: bool,
+) -> RenderedSnippet {
+    let source_lines: Vec<&str> = source.lines().collect();
+    let first = m.line.saturating_sub(1 + context_lines);
+    let last = (m.end_line - 1 + context_lines).min(source_lines.len().saturating_sub(1));
+
+    let gutter_width = (last + 1).to_string().len();
+    let mut lines = Vec::new();
+    let mut text = String::new();
+
+    text.push_str(&format!("{}:{}:{}\n", m.file, m.line, m.column));
+
+    for line_idx in first..=last.max(first) {
+        if source_lines.is_empty() {
+            break;
+        }
+        let number = line_idx + 1;
+        let line_text = source_lines.get(line_idx).copied().unwrap_or("").to_string();
+        let is_match_line = number >= m.line && number <= m.end_line;
+
+        let underline = if is_match_line {
+            let start_col = if number == m.line { m.column } else { 1 };
+            let end_col = if number == m.end_line {
+                m.end_column
+            } else {
+                line_text.len() + 1
+            };
+            Some((start_col, end_col))
+        } else {
+            None
+        };
+
+        let gutter = format!("{number:>gutter_width$}");
+        if color && is_match_line {
+            text.push_str(&format!(
+                "{ANSI_CYAN}{gutter}{ANSI_RESET} | {ANSI_BOLD_RED}{line_text}{ANSI_RESET}\n"
+            ));
+        } else {
+            text.push_str(&format!("{gutter} | {line_text}\n"));
+        }
+
+        if let Some((start_col, end_col)) = underline {
+            let padding = " ".repeat(start_col.saturating_sub(1));
+            let carets = "^".repeat(end_col.saturating_sub(start_col).max(1));
+            let marker = format!("{:width$} | {padding}{carets}", "", width = gutter_width);
+            if color {
+                text.push_str(&format!("{ANSI_BOLD_RED}{marker}{ANSI_RESET}\n"));
+            } else {
+                text.push_str(&marker);
+                text.push('\n');
+            }
+        }
+
+        lines.push(RenderedLine {
+            number,
+            text: line_text,
+            underline,
+        });
+    }
+
+    RenderedSnippet {
+        file: m.file.clone(),
+        line: m.line,
+        column: m.column,
+        lines,
+        text,
+    }
+}
+
 /// A single replacement made during an AST-grep replace operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Replacement {
@@ -67,15 +217,35 @@ pub struct Replacement {
     pub replacement: String,
 }
 
+/// A unified diff of the proposed changes to a single file, produced by a
+/// dry-run replace instead of writing the file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileDiff {
+    /// Path to the file the diff applies to (relative to workspace).
+    pub file: String,
+    /// Unified diff text (`@@ -a,b +c,d @@` hunks with a few lines of
+    /// context), ready to display or hand to a patch applier.
+    pub diff: String,
+}
+
 /// Result of an AST-grep replace operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplaceResult {
-    /// List of files that were modified.
+    /// List of files that were modified. In a dry run, these are the files
+    /// that *would* be modified - nothing was written.
     pub files_modified: Vec<String>,
     /// Total number of replacements made.
     pub replacements_count: usize,
     /// Details of each replacement.
     pub changes: Vec<Replacement>,
+    /// Number of matches discarded because they overlapped or were nested
+    /// inside another match (e.g. an inner expression matched by the same
+    /// pattern as its enclosing statement). Lets callers surface something
+    /// like "3 nested matches skipped."
+    pub skipped_nested_matches: usize,
+    /// Per-file unified diffs, populated only when the replace was run with
+    /// `dry_run: true`. Empty for a normal (file-writing) replace.
+    pub diffs: Vec<FileDiff>,
 }
 
 impl ReplaceResult {
@@ -85,6 +255,8 @@ impl ReplaceResult {
             files_modified: Vec::new(),
             replacements_count: 0,
             changes: Vec::new(),
+            skipped_nested_matches: 0,
+            diffs: Vec::new(),
         }
     }
 }
@@ -94,3 +266,79 @@ impl Default for ReplaceResult {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_match() -> SearchMatch {
+        SearchMatch {
+            file: "src/lib.rs".to_string(),
+            line: 3,
+            column: 5,
+            text: "fn foo()".to_string(),
+            end_line: 3,
+            end_column: 13,
+        }
+    }
+
+    #[test]
+    fn test_render_match_single_line_underlines_span() {
+        let source = "line1\nline2\nfn foo() {}\nline4\nline5";
+        let snippet = render_match(source, &sample_match(), 1, false);
+
+        assert_eq!(snippet.lines.len(), 3); // 1 context + match + 1 context
+        let match_line = snippet.lines.iter().find(|l| l.number == 3).unwrap();
+        assert_eq!(match_line.underline, Some((5, 13)));
+        assert!(snippet.text.contains("fn foo() {}"));
+        assert!(snippet.text.contains("^^^^^^^^"));
+        assert!(!snippet.text.contains("\x1b["));
+    }
+
+    #[test]
+    fn test_render_match_color_adds_ansi_codes() {
+        let source = "fn foo() {}";
+        let m = SearchMatch {
+            line: 1,
+            end_line: 1,
+            ..sample_match()
+        };
+        let snippet = render_match(source, &m, 0, true);
+
+        assert!(snippet.text.contains("\x1b["));
+    }
+
+    #[test]
+    fn test_render_match_multiline_underlines_every_line() {
+        let source = "fn foo(\n  a: i32,\n) -> i32 {\n  a\n}";
+        let m = SearchMatch {
+            file: "src/lib.rs".to_string(),
+            line: 1,
+            column: 1,
+            text: "fn foo(\n  a: i32,\n) -> i32 {\n  a\n}".to_string(),
+            end_line: 5,
+            end_column: 2,
+        };
+        let snippet = render_match(source, &m, 0, false);
+
+        assert_eq!(snippet.lines.len(), 5);
+        assert!(snippet.lines.iter().all(|l| l.underline.is_some()));
+        assert_eq!(snippet.lines[0].underline, Some((1, 8)));
+        assert_eq!(snippet.lines[4].underline, Some((1, 2)));
+    }
+
+    #[test]
+    fn test_search_result_render_uses_cached_sources() {
+        let mut result = SearchResult::new();
+        result.matches.push(sample_match());
+        result
+            .sources
+            .insert("src/lib.rs".to_string(), "line1\nline2\nfn foo() {}\n".to_string());
+
+        let (text, snippets) = result.render(0, false);
+
+        assert_eq!(snippets.len(), 1);
+        assert!(text.contains("src/lib.rs:3:5"));
+        assert!(text.contains("fn foo()"));
+    }
+}
@@ -0,0 +1,544 @@
+//! YAML rule-based search and replace.
+//!
+//! A bare pattern string is enough for one-off matches, but refactors often
+//! need to constrain *which* matches count - "only if `$NAME` looks like a
+//! constant", "only inside a `describe(...)` block". A [`RuleConfig`] bundles
+//! one or more [`Rule`]s, each a pattern plus a `constraints` map from
+//! metavariable name to [`MetaVarConstraint`], and [`search_with_rule`] /
+//! [`replace_with_rule`] run every rule against a file in one pass, filtering
+//! out matches whose bound metavariables fail any constraint.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use ast_grep_core::Doc;
+use ast_grep_language::{LanguageExt, SupportLang};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::{
+    detect_language, generate_replacement, parse_language, walk, ReplaceResult, Replacement,
+    SearchMatch, SearchResult, WalkOptions,
+};
+
+/// A YAML document describing one or more [`Rule`]s to run in a single pass.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RuleConfig {
+    pub rules: Vec<Rule>,
+}
+
+/// A single pattern, optional replacement, and the constraints its captured
+/// metavariables must satisfy for a match to count.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rule {
+    /// AST pattern to match (e.g. `"console.log($MSG)"`).
+    pub pattern: String,
+    /// Replacement template. Only used by [`replace_with_rule`]; rules
+    /// without one are skipped when replacing, but still count toward
+    /// [`search_with_rule`] results.
+    #[serde(default)]
+    pub replacement: Option<String>,
+    /// Per-metavariable constraints a match must satisfy (keyed by name,
+    /// without the leading `$`).
+    #[serde(default)]
+    pub constraints: std::collections::BTreeMap<String, MetaVarConstraint>,
+}
+
+/// Constraints a single captured metavariable must satisfy. All populated
+/// fields must pass for the match to be kept.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MetaVarConstraint {
+    /// The metavariable's captured text must match this regex.
+    pub regex: Option<String>,
+    /// The metavariable's captured node's AST kind must equal this string.
+    pub kind: Option<String>,
+    /// The metavariable's captured text must appear inside a node matching
+    /// this pattern, i.e. the constraint pattern's range must contain the
+    /// metavariable's range.
+    pub inside: Option<String>,
+    /// The metavariable's captured range must contain a node matching this
+    /// pattern.
+    pub has: Option<String>,
+    /// The metavariable's range must end at or before a node matching this
+    /// pattern starts (the metavariable "precedes" that node).
+    pub precedes: Option<String>,
+    /// The metavariable's range must start at or after a node matching this
+    /// pattern ends (the metavariable "follows" that node).
+    pub follows: Option<String>,
+}
+
+/// Parse `rule_yaml` and search for its rules' matches, honoring the same
+/// file/directory resolution and ignore handling as [`crate::search`].
+pub fn search_with_rule(
+    workspace: &Path,
+    rule_yaml: &str,
+    path: Option<&str>,
+    language: Option<&str>,
+) -> Result<SearchResult> {
+    let config: RuleConfig = serde_yaml::from_str(rule_yaml).context("invalid rule YAML")?;
+
+    let target_path = match path {
+        Some(p) => workspace.join(p),
+        None => workspace.to_path_buf(),
+    };
+    let lang = language.and_then(parse_language);
+
+    let mut result = SearchResult::new();
+
+    let files = if target_path.is_file() {
+        vec![target_path]
+    } else if target_path.is_dir() {
+        walk::walk_files(&target_path, &WalkOptions::default())?
+    } else {
+        anyhow::bail!("Path does not exist: {}", target_path.display());
+    };
+
+    for file_path in &files {
+        let file_lang = match lang.or_else(|| file_path.to_str().and_then(detect_language)) {
+            Some(l) => l,
+            None => continue,
+        };
+
+        let source = fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+        let relative_path = file_path
+            .strip_prefix(workspace)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .to_string();
+
+        let matches_before = result.matches.len();
+        search_source_with_rules(&source, &config, file_lang, &relative_path, &mut result);
+
+        if result.matches.len() > matches_before {
+            result.sources.insert(relative_path, source);
+        }
+        result.files_searched += 1;
+    }
+
+    Ok(result)
+}
+
+fn search_source_with_rules(
+    source: &str,
+    config: &RuleConfig,
+    lang: SupportLang,
+    file_path: &str,
+    result: &mut SearchResult,
+) {
+    let grep = lang.ast_grep(source);
+    let root = grep.root();
+
+    for rule in &config.rules {
+        for node_match in root.find_all(rule.pattern.as_str()) {
+            if !satisfies_constraints(&node_match, &rule.constraints, source, lang) {
+                continue;
+            }
+
+            let start = node_match.start_pos();
+            let end = node_match.end_pos();
+            let start_point = start.byte_point();
+            let end_point = end.byte_point();
+
+            result.matches.push(SearchMatch {
+                file: file_path.to_string(),
+                line: start_point.0 + 1,
+                column: start_point.1 + 1,
+                text: node_match.text().to_string(),
+                end_line: end_point.0 + 1,
+                end_column: end_point.1 + 1,
+            });
+        }
+    }
+}
+
+/// Parse `rule_yaml` and apply its rules' replacements, honoring the same
+/// file/directory resolution and ignore handling as [`crate::replace`].
+pub fn replace_with_rule(
+    workspace: &Path,
+    rule_yaml: &str,
+    path: Option<&str>,
+    language: Option<&str>,
+) -> Result<ReplaceResult> {
+    let config: RuleConfig = serde_yaml::from_str(rule_yaml).context("invalid rule YAML")?;
+
+    let target_path = match path {
+        Some(p) => workspace.join(p),
+        None => workspace.to_path_buf(),
+    };
+    let lang = language.and_then(parse_language);
+
+    let mut result = ReplaceResult::new();
+
+    let files = if target_path.is_file() {
+        vec![target_path]
+    } else if target_path.is_dir() {
+        walk::walk_files(&target_path, &WalkOptions::default())?
+    } else {
+        anyhow::bail!("Path does not exist: {}", target_path.display());
+    };
+
+    for file_path in &files {
+        let file_lang = match lang.or_else(|| file_path.to_str().and_then(detect_language)) {
+            Some(l) => l,
+            None => continue,
+        };
+
+        let source = fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+        let relative_path = file_path
+            .strip_prefix(workspace)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .to_string();
+
+        let (new_source, changes, skipped_nested) =
+            replace_source_with_rules(&source, &config, file_lang, &relative_path);
+
+        result.skipped_nested_matches += skipped_nested;
+
+        if !changes.is_empty() {
+            fs::write(file_path, &new_source)
+                .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
+
+            result.files_modified.push(relative_path);
+            result.replacements_count += changes.len();
+            result.changes.extend(changes);
+        }
+    }
+
+    Ok(result)
+}
+
+fn replace_source_with_rules<'a>(
+    source: &str,
+    config: &'a RuleConfig,
+    lang: SupportLang,
+    file_path: &str,
+) -> (String, Vec<Replacement>, usize) {
+    let grep = lang.ast_grep(source);
+    let root = grep.root();
+
+    // Collect matches across all rules that have a replacement template,
+    // keeping each match paired with the template that produced it.
+    let mut matches = Vec::new();
+    for rule in &config.rules {
+        let Some(replacement) = rule.replacement.as_ref() else {
+            continue;
+        };
+        for node_match in root.find_all(rule.pattern.as_str()) {
+            if satisfies_constraints(&node_match, &rule.constraints, source, lang) {
+                matches.push((node_match, replacement));
+            }
+        }
+    }
+
+    let (accepted, skipped_nested) = resolve_overlapping_rule_matches(matches);
+
+    let mut new_source = source.to_string();
+    let mut changes = Vec::new();
+
+    for (node_match, replacement) in accepted {
+        let range = node_match.range();
+        let original = node_match.text().to_string();
+        let start = node_match.start_pos();
+        let start_point = start.byte_point();
+
+        let replaced = generate_replacement(&node_match, replacement, lang);
+        new_source.replace_range(range.start..range.end, &replaced);
+
+        changes.push(Replacement {
+            file: file_path.to_string(),
+            line: start_point.0 + 1,
+            original,
+            replacement: replaced,
+        });
+    }
+
+    changes.reverse();
+
+    (new_source, changes, skipped_nested)
+}
+
+/// Same overlap-resolution rule as [`resolve_overlapping_matches`] (ascending
+/// sort by start, keep a match only if it starts at or past the last kept
+/// match's end, drop zero-width matches), but over `(match, template)` pairs
+/// so the winning rule's replacement template travels with its match.
+///
+/// Kept separate from the shared single-pattern version rather than making
+/// that one generic over an extra payload - it's used by the plain
+/// `replace`/`replace_with_options` path too, which has no per-match
+/// template to carry.
+fn resolve_overlapping_rule_matches<'a, D: Doc>(
+    mut matches: Vec<(ast_grep_core::NodeMatch<D>, &'a String)>,
+) -> (Vec<(ast_grep_core::NodeMatch<D>, &'a String)>, usize) {
+    matches.sort_by(|a, b| a.0.range().start.cmp(&b.0.range().start));
+
+    let mut accepted = Vec::new();
+    let mut skipped = 0;
+    let mut last_end = 0;
+
+    for (node_match, template) in matches {
+        let range = node_match.range();
+        if range.start == range.end || range.start < last_end {
+            skipped += 1;
+            continue;
+        }
+        last_end = range.end;
+        accepted.push((node_match, template));
+    }
+
+    accepted.sort_by(|a, b| b.0.range().start.cmp(&a.0.range().start));
+
+    (accepted, skipped)
+}
+
+/// Check whether `node_match`'s bound metavariables satisfy every
+/// constraint in `constraints`. An unconstrained metavariable (absent from
+/// the map) always passes.
+fn satisfies_constraints<D: Doc>(
+    node_match: &ast_grep_core::NodeMatch<D>,
+    constraints: &std::collections::BTreeMap<String, MetaVarConstraint>,
+    source: &str,
+    lang: SupportLang,
+) -> bool {
+    let env = node_match.get_env();
+
+    for (var_name, constraint) in constraints {
+        let Some(bound) = env.get_match(var_name) else {
+            // A constraint on a metavariable the pattern never bound can
+            // never be satisfied.
+            return false;
+        };
+
+        if let Some(pattern) = &constraint.regex {
+            let Ok(re) = Regex::new(pattern) else {
+                return false;
+            };
+            if !re.is_match(&bound.text()) {
+                return false;
+            }
+        }
+
+        if let Some(kind) = &constraint.kind {
+            if bound.kind().to_string() != *kind {
+                return false;
+            }
+        }
+
+        let bound_range = bound.range();
+
+        if let Some(pattern) = &constraint.inside {
+            if !pattern_ranges(source, pattern, lang)
+                .iter()
+                .any(|r| r.start <= bound_range.start && r.end >= bound_range.end)
+            {
+                return false;
+            }
+        }
+
+        if let Some(pattern) = &constraint.has {
+            if !pattern_ranges(source, pattern, lang)
+                .iter()
+                .any(|r| bound_range.start <= r.start && bound_range.end >= r.end)
+            {
+                return false;
+            }
+        }
+
+        if let Some(pattern) = &constraint.precedes {
+            if !pattern_ranges(source, pattern, lang)
+                .iter()
+                .any(|r| bound_range.end <= r.start)
+            {
+                return false;
+            }
+        }
+
+        if let Some(pattern) = &constraint.follows {
+            if !pattern_ranges(source, pattern, lang)
+                .iter()
+                .any(|r| r.end <= bound_range.start)
+            {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Find every match of `pattern` in `source` and return their byte ranges.
+///
+/// Relational constraints (`inside`/`has`/`precedes`/`follows`) are checked
+/// purely by range geometry against a fresh parse of `source`, rather than
+/// walking the AST's ancestor/sibling links directly - simpler to reason
+/// about, and works the same regardless of how `ast_grep_core` exposes tree
+/// navigation internally.
+fn pattern_ranges(source: &str, pattern: &str, lang: SupportLang) -> Vec<std::ops::Range<usize>> {
+    let grep = lang.ast_grep(source);
+    grep.root()
+        .find_all(pattern)
+        .map(|m| m.range())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_search_with_rule_regex_constraint() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("a.js"),
+            "const FOO_BAR = 1;\nconst fooBar = 2;\n",
+        )
+        .unwrap();
+
+        let rule_yaml = r#"
+rules:
+  - pattern: "const $NAME = $VAL"
+    constraints:
+      NAME:
+        regex: "^[A-Z_]+$"
+"#;
+
+        let result = search_with_rule(tmp.path(), rule_yaml, None, Some("javascript")).unwrap();
+        assert_eq!(result.matches.len(), 1);
+        assert!(result.matches[0].text.contains("FOO_BAR"));
+    }
+
+    #[test]
+    fn test_search_with_rule_runs_multiple_rules_in_one_pass() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("a.js"),
+            "console.log('a');\nconsole.warn('b');\n",
+        )
+        .unwrap();
+
+        let rule_yaml = r#"
+rules:
+  - pattern: "console.log($MSG)"
+  - pattern: "console.warn($MSG)"
+"#;
+
+        let result = search_with_rule(tmp.path(), rule_yaml, None, Some("javascript")).unwrap();
+        assert_eq!(result.matches.len(), 2);
+    }
+
+    #[test]
+    fn test_search_with_rule_rejects_unbound_metavariable_constraint() {
+        let source_dir = TempDir::new().unwrap();
+        fs::write(source_dir.path().join("a.js"), "foo(1);\n").unwrap();
+
+        let rule_yaml = r#"
+rules:
+  - pattern: "foo($ARG)"
+    constraints:
+      OTHER:
+        regex: "^x$"
+"#;
+
+        let result = search_with_rule(source_dir.path(), rule_yaml, None, Some("javascript")).unwrap();
+        assert!(result.matches.is_empty());
+    }
+
+    #[test]
+    fn test_replace_with_rule_applies_matching_rule_template() {
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("a.js");
+        fs::write(&file, "console.log('hi');\n").unwrap();
+
+        let rule_yaml = r#"
+rules:
+  - pattern: "console.log($MSG)"
+    replacement: "logger.info($MSG)"
+"#;
+
+        let result = replace_with_rule(tmp.path(), rule_yaml, None, Some("javascript")).unwrap();
+        assert_eq!(result.replacements_count, 1);
+        let new_source = fs::read_to_string(&file).unwrap();
+        assert!(new_source.contains("logger.info('hi')"));
+    }
+
+    #[test]
+    fn test_satisfies_constraints_inside_relational() {
+        let source = "function outer() { const x = 1; }\nconst y = 2;";
+        let grep = SupportLang::JavaScript.ast_grep(source);
+        let root = grep.root();
+        let node_match = root.find("const $NAME = $VAL").unwrap();
+
+        let mut constraints = std::collections::BTreeMap::new();
+        constraints.insert(
+            "NAME".to_string(),
+            MetaVarConstraint {
+                inside: Some("function $FN() { $$$BODY }".to_string()),
+                ..Default::default()
+            },
+        );
+
+        // `find` returns the first textual match in file order, which is the
+        // `x` declaration inside `outer`.
+        assert!(satisfies_constraints(
+            &node_match,
+            &constraints,
+            source,
+            SupportLang::JavaScript
+        ));
+    }
+
+    #[test]
+    fn test_satisfies_constraints_precedes_relational() {
+        let source = "const x = 1;\nfoo();\n";
+        let grep = SupportLang::JavaScript.ast_grep(source);
+        let root = grep.root();
+        let node_match = root.find("const $NAME = $VAL").unwrap();
+
+        let mut constraints = std::collections::BTreeMap::new();
+        constraints.insert(
+            "NAME".to_string(),
+            MetaVarConstraint {
+                precedes: Some("foo()".to_string()),
+                ..Default::default()
+            },
+        );
+
+        // `x` is declared before the `foo()` call, so it precedes it.
+        assert!(satisfies_constraints(
+            &node_match,
+            &constraints,
+            source,
+            SupportLang::JavaScript
+        ));
+    }
+
+    #[test]
+    fn test_satisfies_constraints_follows_relational() {
+        let source = "foo();\nconst y = 2;\n";
+        let grep = SupportLang::JavaScript.ast_grep(source);
+        let root = grep.root();
+        let node_match = root.find("const $NAME = $VAL").unwrap();
+
+        let mut constraints = std::collections::BTreeMap::new();
+        constraints.insert(
+            "NAME".to_string(),
+            MetaVarConstraint {
+                follows: Some("foo()".to_string()),
+                ..Default::default()
+            },
+        );
+
+        // `y` is declared after the `foo()` call, so it follows it.
+        assert!(satisfies_constraints(
+            &node_match,
+            &constraints,
+            source,
+            SupportLang::JavaScript
+        ));
+    }
+}
@@ -9,7 +9,13 @@ use anyhow::Result;
 use qbit_core::Tool;
 use serde_json::{json, Value};
 
-use crate::{replace, search};
+use crate::rule::{replace_with_rule, search_with_rule};
+use crate::{replace_with_options, search_with_options, WalkOptions};
+
+/// Get an optional unsigned-integer argument from JSON, defaulting to `default`.
+fn get_optional_u64(args: &Value, key: &str, default: u64) -> u64 {
+    args.get(key).and_then(|v| v.as_u64()).unwrap_or(default)
+}
 
 /// Get a required string argument from JSON.
 fn get_required_str<'a>(args: &'a Value, key: &str) -> Result<&'a str, Value> {
@@ -23,6 +29,34 @@ fn get_optional_str<'a>(args: &'a Value, key: &str) -> Option<&'a str> {
     args.get(key).and_then(|v| v.as_str())
 }
 
+/// Get an optional array-of-strings argument from JSON, defaulting to empty.
+fn get_optional_str_array(args: &Value, key: &str) -> Vec<String> {
+    args.get(key)
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Get an optional bool argument from JSON, defaulting to `false`.
+fn get_optional_bool(args: &Value, key: &str) -> bool {
+    args.get(key).and_then(|v| v.as_bool()).unwrap_or(false)
+}
+
+/// Build a [`WalkOptions`] from the `include`/`exclude`/`file_type`/
+/// `no_ignore` arguments shared by both tools.
+fn walk_options_from_args(args: &Value) -> WalkOptions {
+    WalkOptions {
+        include: get_optional_str_array(args, "include"),
+        exclude: get_optional_str_array(args, "exclude"),
+        file_types: get_optional_str_array(args, "file_type"),
+        no_ignore: get_optional_bool(args, "no_ignore"),
+    }
+}
+
 // ============================================================================
 // ast_grep (search)
 // ============================================================================
@@ -41,7 +75,10 @@ impl Tool for AstGrepTool {
          Use meta-variables like $VAR to match any expression. \
          Examples: 'fn $NAME($$$ARGS) { $$$BODY }' matches Rust functions, \
          'console.log($MSG)' matches JS logging calls. \
-         Pattern must include complete syntactic structures."
+         Pattern must include complete syntactic structures. \
+         For matches that need per-metavariable constraints (regex, AST kind, \
+         inside/has/precedes/follows another pattern), pass a YAML rule via \
+         `rule` instead of `pattern`."
     }
 
     fn parameters(&self) -> Value {
@@ -50,7 +87,11 @@ impl Tool for AstGrepTool {
             "properties": {
                 "pattern": {
                     "type": "string",
-                    "description": "AST pattern to search for. Use $VAR for single nodes, $$$VAR for multiple nodes. Must be a complete syntactic structure."
+                    "description": "AST pattern to search for. Use $VAR for single nodes, $$$VAR for multiple nodes. Must be a complete syntactic structure. Ignored if `rule` is set."
+                },
+                "rule": {
+                    "type": "string",
+                    "description": "YAML rule config (one or more `{pattern, constraints}` rules) for matches that need per-metavariable constraints. Takes precedence over `pattern` if both are set. `include`, `exclude`, `file_type` and `no_ignore` are not applied in rule mode. Example:\n rules:\n   - pattern: \"console.log($MSG)\"\n     constraints:\n       MSG:\n         regex: \"^user\""
                 },
                 "path": {
                     "type": "string",
@@ -60,34 +101,86 @@ impl Tool for AstGrepTool {
                     "type": "string",
                     "enum": ["rust", "typescript", "javascript", "python", "go", "java", "c", "cpp"],
                     "description": "Language for pattern parsing. Auto-detected from file extension if not specified."
+                },
+                "include": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Only search files matching at least one of these glob patterns (e.g. [\"**/*.rs\"]). Only applies to directory searches."
+                },
+                "exclude": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Skip files matching any of these glob patterns, even if they matched include. Only applies to directory searches."
+                },
+                "file_type": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Only search files of these types (e.g. [\"rust\", \"js\"]). Only applies to directory searches."
+                },
+                "no_ignore": {
+                    "type": "boolean",
+                    "description": "Disable .gitignore/.ignore/global-exclude handling and search every file. Defaults to false (ignored files are skipped)."
+                },
+                "context_lines": {
+                    "type": "integer",
+                    "description": "Lines of leading/trailing context to include around each match in the rendered snippet. Defaults to 2."
                 }
             },
-            "required": ["pattern"]
+            "required": []
         })
     }
 
     async fn execute(&self, args: Value, workspace: &Path) -> Result<Value> {
+        let path = get_optional_str(&args, "path");
+        let language = get_optional_str(&args, "language");
+        let context_lines = get_optional_u64(&args, "context_lines", 2) as usize;
+
+        if let Some(rule_yaml) = get_optional_str(&args, "rule") {
+            return match search_with_rule(workspace, rule_yaml, path, language) {
+                Ok(result) => {
+                    let (rendered, _snippets) = result.render(context_lines, false);
+                    Ok(json!({
+                        "matches": result.matches.iter().map(|m| json!({
+                            "file": m.file,
+                            "line": m.line,
+                            "column": m.column,
+                            "text": m.text,
+                            "end_line": m.end_line,
+                            "end_column": m.end_column
+                        })).collect::<Vec<_>>(),
+                        "count": result.matches.len(),
+                        "files_searched": result.files_searched,
+                        "rendered": rendered
+                    }))
+                }
+                Err(e) => Ok(json!({"error": e.to_string()})),
+            };
+        }
+
         let pattern = match get_required_str(&args, "pattern") {
             Ok(p) => p,
             Err(e) => return Ok(e),
         };
 
-        let path = get_optional_str(&args, "path");
-        let language = get_optional_str(&args, "language");
-
-        match search(workspace, pattern, path, language) {
-            Ok(result) => Ok(json!({
-                "matches": result.matches.iter().map(|m| json!({
-                    "file": m.file,
-                    "line": m.line,
-                    "column": m.column,
-                    "text": m.text,
-                    "end_line": m.end_line,
-                    "end_column": m.end_column
-                })).collect::<Vec<_>>(),
-                "count": result.matches.len(),
-                "files_searched": result.files_searched
-            })),
+        let walk_options = walk_options_from_args(&args);
+
+        match search_with_options(workspace, pattern, path, language, &walk_options, None) {
+            Ok(result) => {
+                let (rendered, _snippets) = result.render(context_lines, false);
+                Ok(json!({
+                    "matches": result.matches.iter().map(|m| json!({
+                        "file": m.file,
+                        "line": m.line,
+                        "column": m.column,
+                        "text": m.text,
+                        "end_line": m.end_line,
+                        "end_column": m.end_column
+                    })).collect::<Vec<_>>(),
+                    "count": result.matches.len(),
+                    "files_searched": result.files_searched,
+                    "rendered": rendered
+                }))
+            }
             Err(e) => Ok(json!({"error": e.to_string()})),
         }
     }
@@ -112,7 +205,10 @@ impl Tool for AstGrepReplaceTool {
         "Replace code patterns using AST-aware rewriting. \
          Captured meta-variables from the pattern can be used in the replacement. \
          Example: pattern='console.log($MSG)' replacement='logger.info($MSG)' \
-         transforms logging calls."
+         transforms logging calls. \
+         For replacements that need per-metavariable constraints, pass a YAML \
+         rule via `rule` instead of `pattern`/`replacement` (each rule's own \
+         `replacement` field is used; rules without one are skipped)."
     }
 
     fn parameters(&self) -> Value {
@@ -121,11 +217,15 @@ impl Tool for AstGrepReplaceTool {
             "properties": {
                 "pattern": {
                     "type": "string",
-                    "description": "AST pattern to match. Use $VAR for captures."
+                    "description": "AST pattern to match. Use $VAR for captures. Ignored if `rule` is set."
                 },
                 "replacement": {
                     "type": "string",
-                    "description": "Replacement template. Use captured $VAR names from pattern."
+                    "description": "Replacement template. Use captured $VAR names from pattern. Ignored if `rule` is set."
+                },
+                "rule": {
+                    "type": "string",
+                    "description": "YAML rule config (one or more `{pattern, replacement, constraints}` rules) for replacements that need per-metavariable constraints. Takes precedence over `pattern`/`replacement` if both are set. `dry_run` is not supported in rule mode, and `include`, `exclude`, `file_type` and `no_ignore` are not applied."
                 },
                 "path": {
                     "type": "string",
@@ -135,39 +235,102 @@ impl Tool for AstGrepReplaceTool {
                     "type": "string",
                     "enum": ["rust", "typescript", "javascript", "python", "go", "java", "c", "cpp"],
                     "description": "Language for pattern parsing. Auto-detected if not specified."
+                },
+                "include": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Only modify files matching at least one of these glob patterns (e.g. [\"**/*.rs\"]). Only applies to directory replaces."
+                },
+                "exclude": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Skip files matching any of these glob patterns, even if they matched include. Only applies to directory replaces."
+                },
+                "file_type": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Only modify files of these types (e.g. [\"rust\", \"js\"]). Only applies to directory replaces."
+                },
+                "no_ignore": {
+                    "type": "boolean",
+                    "description": "Disable .gitignore/.ignore/global-exclude handling and modify every file. Defaults to false (ignored files are skipped)."
+                },
+                "dry_run": {
+                    "type": "boolean",
+                    "description": "Preview the replacement as a unified diff per file instead of writing changes to disk. Defaults to false."
                 }
             },
-            "required": ["pattern", "replacement", "path"]
+            "required": ["path"]
         })
     }
 
     async fn execute(&self, args: Value, workspace: &Path) -> Result<Value> {
-        let pattern = match get_required_str(&args, "pattern") {
+        let path = match get_required_str(&args, "path") {
             Ok(p) => p,
             Err(e) => return Ok(e),
         };
 
-        let replacement_str = match get_required_str(&args, "replacement") {
-            Ok(r) => r,
-            Err(e) => return Ok(e),
-        };
+        let language = get_optional_str(&args, "language");
 
-        let path = match get_required_str(&args, "path") {
+        if let Some(rule_yaml) = get_optional_str(&args, "rule") {
+            if get_optional_bool(&args, "dry_run") {
+                return Ok(json!({"error": "dry_run is not supported with `rule`"}));
+            }
+            return match replace_with_rule(workspace, rule_yaml, Some(path), language) {
+                Ok(result) => Ok(json!({
+                    "files_modified": result.files_modified,
+                    "replacements_count": result.replacements_count,
+                    "skipped_nested_matches": result.skipped_nested_matches,
+                    "dry_run": false,
+                    "changes": result.changes.iter().map(|c| json!({
+                        "file": c.file,
+                        "line": c.line,
+                        "original": c.original,
+                        "replacement": c.replacement
+                    })).collect::<Vec<_>>(),
+                    "diffs": []
+                })),
+                Err(e) => Ok(json!({"error": e.to_string()})),
+            };
+        }
+
+        let pattern = match get_required_str(&args, "pattern") {
             Ok(p) => p,
             Err(e) => return Ok(e),
         };
 
-        let language = get_optional_str(&args, "language");
+        let replacement_str = match get_required_str(&args, "replacement") {
+            Ok(r) => r,
+            Err(e) => return Ok(e),
+        };
 
-        match replace(workspace, pattern, replacement_str, path, language) {
+        let walk_options = walk_options_from_args(&args);
+        let dry_run = get_optional_bool(&args, "dry_run");
+
+        match replace_with_options(
+            workspace,
+            pattern,
+            replacement_str,
+            path,
+            language,
+            &walk_options,
+            None,
+            dry_run,
+        ) {
             Ok(result) => Ok(json!({
                 "files_modified": result.files_modified,
                 "replacements_count": result.replacements_count,
+                "skipped_nested_matches": result.skipped_nested_matches,
+                "dry_run": dry_run,
                 "changes": result.changes.iter().map(|c| json!({
                     "file": c.file,
                     "line": c.line,
                     "original": c.original,
                     "replacement": c.replacement
+                })).collect::<Vec<_>>(),
+                "diffs": result.diffs.iter().map(|d| json!({
+                    "file": d.file,
+                    "diff": d.diff
                 })).collect::<Vec<_>>()
             })),
             Err(e) => Ok(json!({"error": e.to_string()})),
@@ -292,4 +455,64 @@ mod tests {
         assert!(result.get("error").is_none());
         assert_eq!(result["count"].as_i64().unwrap(), 2);
     }
+
+    #[tokio::test]
+    async fn test_ast_grep_tool_respects_gitignore_by_default() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".gitignore"), "ignored.js\n").unwrap();
+        fs::write(tmp.path().join("ignored.js"), "console.log('a');").unwrap();
+        fs::write(tmp.path().join("kept.js"), "console.log('b');").unwrap();
+
+        let tool = AstGrepTool;
+        let result = tool
+            .execute(
+                json!({
+                    "pattern": "console.log($MSG)",
+                    "language": "javascript"
+                }),
+                tmp.path(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result["count"].as_i64().unwrap(), 1);
+
+        // `no_ignore` re-includes the gitignored file.
+        let result = tool
+            .execute(
+                json!({
+                    "pattern": "console.log($MSG)",
+                    "language": "javascript",
+                    "no_ignore": true
+                }),
+                tmp.path(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result["count"].as_i64().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_ast_grep_tool_include_glob_filter() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("a.js"), "console.log('a');").unwrap();
+        fs::create_dir(tmp.path().join("vendor")).unwrap();
+        fs::write(tmp.path().join("vendor/b.js"), "console.log('b');").unwrap();
+
+        let tool = AstGrepTool;
+        let result = tool
+            .execute(
+                json!({
+                    "pattern": "console.log($MSG)",
+                    "language": "javascript",
+                    "exclude": ["**/vendor/**"]
+                }),
+                tmp.path(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result["count"].as_i64().unwrap(), 1);
+    }
 }
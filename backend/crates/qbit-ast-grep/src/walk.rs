@@ -0,0 +1,158 @@
+//! Directory traversal options for [`search_with_options`](crate::search_with_options)
+//! and [`replace_with_options`](crate::replace_with_options).
+//!
+//! By default, traversal honors `.gitignore`, `.ignore`, and global excludes
+//! (via the `ignore` crate, mirroring `qbit::commands::files`), so whole-repo
+//! searches skip `target/`, `.git/`, `node_modules/`, and other build
+//! artifacts instead of blindly walking every file.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::types::TypesBuilder;
+use ignore::WalkBuilder;
+
+/// Options controlling which files a directory search or replace visits.
+#[derive(Debug, Clone, Default)]
+pub struct WalkOptions {
+    /// Only visit files matching at least one of these glob patterns (e.g.
+    /// `"**/*.rs"`). Empty means no include filtering.
+    pub include: Vec<String>,
+    /// Skip files matching any of these glob patterns, even if they matched
+    /// `include`.
+    pub exclude: Vec<String>,
+    /// Only visit files of these `ignore`-crate file types (e.g. `"rust"`,
+    /// `"js"`). Empty means no file-type filtering.
+    pub file_types: Vec<String>,
+    /// Disable `.gitignore`/`.ignore`/global-exclude handling and visit every
+    /// file, including those normally hidden from version control.
+    pub no_ignore: bool,
+}
+
+/// Walk `target_path` recursively, yielding the paths of files that pass
+/// `options`' ignore, glob, and file-type filters.
+pub(crate) fn walk_files(target_path: &Path, options: &WalkOptions) -> Result<Vec<PathBuf>> {
+    let include = compile_globset(&options.include).context("invalid include glob")?;
+    let exclude = compile_globset(&options.exclude).context("invalid exclude glob")?;
+
+    let mut builder = WalkBuilder::new(target_path);
+    builder
+        .hidden(!options.no_ignore)
+        .git_ignore(!options.no_ignore)
+        .git_global(!options.no_ignore)
+        .git_exclude(!options.no_ignore)
+        .ignore(!options.no_ignore)
+        .require_git(false);
+
+    if !options.file_types.is_empty() {
+        let mut types = TypesBuilder::new();
+        types.add_defaults();
+        for file_type in &options.file_types {
+            types.select(file_type);
+        }
+        builder.types(types.build().context("invalid file type filter")?);
+    }
+
+    let files = builder
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().map(|ft| ft.is_file()).unwrap_or(false))
+        .map(|entry| entry.into_path())
+        .filter(|path| include.as_ref().map(|g| g.is_match(path)).unwrap_or(true))
+        .filter(|path| exclude.as_ref().map(|g| !g.is_match(path)).unwrap_or(true))
+        .collect();
+
+    Ok(files)
+}
+
+/// Compile a list of glob patterns into a `GlobSet`, or `None` if the list is
+/// empty (meaning "no filter").
+fn compile_globset(patterns: &[String]) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).with_context(|| format!("invalid glob: {pattern}"))?);
+    }
+    Ok(Some(builder.build()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_walk_files_respects_gitignore_by_default() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".gitignore"), "ignored.rs\n").unwrap();
+        fs::write(tmp.path().join("ignored.rs"), "fn a() {}").unwrap();
+        fs::write(tmp.path().join("kept.rs"), "fn b() {}").unwrap();
+
+        let files = walk_files(tmp.path(), &WalkOptions::default()).unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .filter_map(|p| p.file_name())
+            .map(|n| n.to_string_lossy().to_string())
+            .collect();
+
+        assert!(names.contains(&"kept.rs".to_string()));
+        assert!(!names.contains(&"ignored.rs".to_string()));
+    }
+
+    #[test]
+    fn test_walk_files_no_ignore_includes_everything() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".gitignore"), "ignored.rs\n").unwrap();
+        fs::write(tmp.path().join("ignored.rs"), "fn a() {}").unwrap();
+
+        let options = WalkOptions {
+            no_ignore: true,
+            ..Default::default()
+        };
+        let files = walk_files(tmp.path(), &options).unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .filter_map(|p| p.file_name())
+            .map(|n| n.to_string_lossy().to_string())
+            .collect();
+
+        assert!(names.contains(&"ignored.rs".to_string()));
+    }
+
+    #[test]
+    fn test_walk_files_include_glob_filters_by_extension() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("a.rs"), "fn a() {}").unwrap();
+        fs::write(tmp.path().join("b.js"), "function b() {}").unwrap();
+
+        let options = WalkOptions {
+            include: vec!["**/*.rs".to_string()],
+            ..Default::default()
+        };
+        let files = walk_files(tmp.path(), &options).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].file_name().unwrap(), "a.rs");
+    }
+
+    #[test]
+    fn test_walk_files_exclude_glob_removes_matches() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("a.rs"), "fn a() {}").unwrap();
+        fs::write(tmp.path().join("a_test.rs"), "fn a_test() {}").unwrap();
+
+        let options = WalkOptions {
+            exclude: vec!["**/*_test.rs".to_string()],
+            ..Default::default()
+        };
+        let files = walk_files(tmp.path(), &options).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].file_name().unwrap(), "a.rs");
+    }
+}
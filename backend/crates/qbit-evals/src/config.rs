@@ -18,6 +18,8 @@ pub enum EvalProvider {
     VertexClaude,
     /// Z.AI GLM-4.7
     Zai,
+    /// OpenAI, or any OpenAI-compatible API reachable via a custom base URL.
+    OpenAi,
 }
 
 impl fmt::Display for EvalProvider {
@@ -25,6 +27,40 @@ impl fmt::Display for EvalProvider {
         match self {
             EvalProvider::VertexClaude => write!(f, "vertex-claude"),
             EvalProvider::Zai => write!(f, "zai"),
+            EvalProvider::OpenAi => write!(f, "openai"),
+        }
+    }
+}
+
+/// A capability a scenario may depend on that not every provider supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// Native server-side web search (e.g. Claude's `web_search` tool).
+    WebSearch,
+    /// Delegating work to sub-agents.
+    SubAgents,
+    /// Semantic code indexing (`indexer` tools).
+    Indexer,
+    /// Extended/interleaved thinking.
+    ExtendedThinking,
+}
+
+impl EvalProvider {
+    /// Capabilities this provider advertises support for.
+    ///
+    /// Scenarios declare what they need via `Scenario::required_capabilities()`;
+    /// the eval runner skips scenarios whose requirements aren't met here
+    /// instead of running them and reporting a spurious failure.
+    pub fn capabilities(&self) -> &'static [Capability] {
+        match self {
+            EvalProvider::VertexClaude => &[
+                Capability::WebSearch,
+                Capability::SubAgents,
+                Capability::Indexer,
+                Capability::ExtendedThinking,
+            ],
+            EvalProvider::Zai => &[Capability::SubAgents, Capability::Indexer],
+            EvalProvider::OpenAi => &[],
         }
     }
 }
@@ -36,8 +72,9 @@ impl FromStr for EvalProvider {
         match s.to_lowercase().as_str() {
             "vertex" | "vertex-claude" | "claude" | "anthropic" => Ok(EvalProvider::VertexClaude),
             "zai" | "z.ai" | "glm" | "glm-4.7" => Ok(EvalProvider::Zai),
+            "openai" | "oai" => Ok(EvalProvider::OpenAi),
             _ => anyhow::bail!(
-                "Unknown provider: '{}'. Valid options: vertex-claude, zai",
+                "Unknown provider: '{}'. Valid options: vertex-claude, zai, openai",
                 s
             ),
         }
@@ -62,6 +99,37 @@ pub struct ZaiConfig {
     pub api_key: String,
 }
 
+/// Configuration for OpenAI, or any OpenAI-compatible API.
+#[derive(Debug, Clone)]
+pub struct OpenAiConfig {
+    /// OpenAI (or compatible provider) API key.
+    pub api_key: String,
+    /// Custom base URL for OpenAI-compatible platforms (Groq, OpenRouter,
+    /// Together, Mistral, Fireworks, Perplexity, DeepInfra, Moonshot, ...).
+    /// `None` uses the default `api.openai.com` endpoint.
+    pub api_base: Option<String>,
+    /// Extra models declared in `[ai.openai].models`, to merge over the eval
+    /// harness's built-in model table.
+    pub models: Vec<EvalModelConfig>,
+}
+
+/// A user-declared model for connectivity evals (see `[ai.openai].models`),
+/// letting custom gateways or preview models be validated without a
+/// crate rebuild.
+#[derive(Debug, Clone)]
+pub struct EvalModelConfig {
+    /// Model ID to pass to the API.
+    pub id: String,
+    /// Human-readable label used in eval reports.
+    pub display_name: String,
+    /// Maximum tokens to request. `None` uses the eval harness's default.
+    pub max_tokens: Option<u32>,
+    /// Whether this model accepts the `temperature` parameter.
+    pub supports_temperature: bool,
+    /// Capability tags, e.g. `"text"`, `"vision"`, `"reasoning"`, `"tools"`.
+    pub capabilities: Vec<String>,
+}
+
 /// Configuration for running evaluations.
 #[derive(Debug, Clone)]
 pub struct EvalConfig {
@@ -71,6 +139,8 @@ pub struct EvalConfig {
     pub vertex: Option<VertexConfig>,
     /// Z.AI configuration (if using Z.AI).
     pub zai: Option<ZaiConfig>,
+    /// OpenAI configuration (if using OpenAI or a compatible provider).
+    pub openai: Option<OpenAiConfig>,
 }
 
 impl EvalConfig {
@@ -102,6 +172,7 @@ impl EvalConfig {
                     provider,
                     vertex: Some(vertex),
                     zai: None,
+                    openai: None,
                 })
             }
             EvalProvider::Zai => {
@@ -110,6 +181,16 @@ impl EvalConfig {
                     provider,
                     vertex: None,
                     zai: Some(zai),
+                    openai: None,
+                })
+            }
+            EvalProvider::OpenAi => {
+                let openai = Self::load_openai_config(settings)?;
+                Ok(Self {
+                    provider,
+                    vertex: None,
+                    zai: None,
+                    openai: Some(openai),
                 })
             }
         }
@@ -171,6 +252,46 @@ impl EvalConfig {
         Ok(ZaiConfig { api_key })
     }
 
+    /// Load OpenAI (or OpenAI-compatible) configuration.
+    fn load_openai_config(settings: &QbitSettings) -> Result<OpenAiConfig> {
+        let api_key = get_with_env_fallback(&settings.ai.openai.api_key, &["OPENAI_API_KEY"], None)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "OpenAI API key not configured.\n\n\
+                Set in ~/.qbit/settings.toml:\n\n\
+                [ai.openai]\n\
+                api_key = \"your-api-key\"\n\n\
+                Or set OPENAI_API_KEY environment variable."
+                )
+            })?;
+
+        let api_base = get_with_env_fallback(
+            &settings.ai.openai.base_url,
+            &["OPENAI_API_BASE", "OPENAI_BASE_URL"],
+            None,
+        );
+
+        let models = settings
+            .ai
+            .openai
+            .models
+            .iter()
+            .map(|m| EvalModelConfig {
+                id: m.id.clone(),
+                display_name: m.display_name.clone(),
+                max_tokens: m.max_tokens,
+                supports_temperature: m.supports_temperature,
+                capabilities: m.capabilities.clone(),
+            })
+            .collect();
+
+        Ok(OpenAiConfig {
+            api_key,
+            api_base,
+            models,
+        })
+    }
+
     /// Create config from loaded settings (legacy compatibility).
     pub fn from_settings(settings: &QbitSettings) -> Result<Self> {
         Self::from_settings_for_provider(settings, EvalProvider::default())
@@ -238,6 +359,14 @@ mod tests {
         assert_eq!(EvalProvider::from_str("zai").unwrap(), EvalProvider::Zai);
         assert_eq!(EvalProvider::from_str("z.ai").unwrap(), EvalProvider::Zai);
         assert_eq!(EvalProvider::from_str("glm").unwrap(), EvalProvider::Zai);
+        assert_eq!(
+            EvalProvider::from_str("openai").unwrap(),
+            EvalProvider::OpenAi
+        );
+        assert_eq!(
+            EvalProvider::from_str("oai").unwrap(),
+            EvalProvider::OpenAi
+        );
         assert!(EvalProvider::from_str("unknown").is_err());
     }
 
@@ -245,5 +374,59 @@ mod tests {
     fn test_provider_display() {
         assert_eq!(EvalProvider::VertexClaude.to_string(), "vertex-claude");
         assert_eq!(EvalProvider::Zai.to_string(), "zai");
+        assert_eq!(EvalProvider::OpenAi.to_string(), "openai");
+    }
+
+    #[test]
+    fn test_eval_config_openai_with_custom_base_url() {
+        let mut settings = QbitSettings::default();
+        settings.ai.openai.api_key = Some("test-key".to_string());
+        settings.ai.openai.base_url = Some("https://api.groq.com/openai/v1".to_string());
+
+        let config =
+            EvalConfig::from_settings_for_provider(&settings, EvalProvider::OpenAi).unwrap();
+        let openai = config.openai.unwrap();
+        assert_eq!(openai.api_key, "test-key");
+        assert_eq!(
+            openai.api_base.as_deref(),
+            Some("https://api.groq.com/openai/v1")
+        );
+    }
+
+    #[test]
+    fn test_eval_config_openai_missing_api_key() {
+        let settings = QbitSettings::default();
+
+        let result = EvalConfig::from_settings_for_provider(&settings, EvalProvider::OpenAi);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("API key not configured"));
+    }
+
+    #[test]
+    fn test_eval_config_openai_with_custom_models() {
+        let mut settings = QbitSettings::default();
+        settings.ai.openai.api_key = Some("test-key".to_string());
+        settings.ai.openai.models = vec![qbit_settings::schema::OpenAiEvalModelSetting {
+            id: "gpt-5.3-preview".to_string(),
+            display_name: "GPT 5.3 Preview".to_string(),
+            max_tokens: Some(512),
+            supports_temperature: true,
+            capabilities: vec!["text".to_string(), "tools".to_string()],
+        }];
+
+        let config =
+            EvalConfig::from_settings_for_provider(&settings, EvalProvider::OpenAi).unwrap();
+        let openai = config.openai.unwrap();
+        assert_eq!(openai.models.len(), 1);
+        assert_eq!(openai.models[0].id, "gpt-5.3-preview");
+        assert_eq!(openai.models[0].max_tokens, Some(512));
+        assert!(openai.models[0].supports_temperature);
+        assert_eq!(
+            openai.models[0].capabilities,
+            vec!["text".to_string(), "tools".to_string()]
+        );
     }
 }
@@ -80,3 +80,194 @@ impl Metric for CodeCorrectnessMetric {
         }
     }
 }
+
+/// A single diagnostic parsed out of `cargo`'s `--message-format=json` stream.
+#[derive(Debug, Clone)]
+pub struct CompileDiagnostic {
+    /// `"error"`, `"warning"`, etc.
+    pub level: String,
+    /// The diagnostic's lint/error code, if any (e.g. `"E0308"`).
+    pub code: Option<String>,
+    /// Human-readable diagnostic message.
+    pub message: String,
+    /// Whether rustc offered a machine-applicable suggestion (rustfix-style)
+    /// for this diagnostic.
+    pub machine_applicable: bool,
+}
+
+/// Metric that runs `cargo build --message-format=json` (or `cargo check`) in
+/// the testbed and scores based on the compiler's own diagnostics, rather
+/// than trusting an LLM judge to eyeball whether generated Rust compiles.
+pub struct CompileCheckMetric {
+    /// Name of this metric instance.
+    name: String,
+    /// Whether to run `cargo check` instead of `cargo build` (faster, no codegen).
+    check_only: bool,
+    /// Fail unless the diagnostic stream contains zero `error`-level entries.
+    require_zero_errors: bool,
+    /// If non-empty, fail unless every one of these diagnostic codes appears
+    /// at least once (e.g. to assert a lint actually fired).
+    expect_codes: Vec<String>,
+}
+
+impl CompileCheckMetric {
+    /// Create a metric that runs `cargo build` and requires zero compiler errors.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            check_only: false,
+            require_zero_errors: true,
+            expect_codes: Vec::new(),
+        }
+    }
+
+    /// Use `cargo check` instead of `cargo build` (skips codegen, faster).
+    pub fn check_only(mut self) -> Self {
+        self.check_only = true;
+        self
+    }
+
+    /// Don't fail on compiler errors; only use this to collect diagnostics
+    /// (e.g. when paired with `expect_codes`).
+    pub fn allow_errors(mut self) -> Self {
+        self.require_zero_errors = false;
+        self
+    }
+
+    /// Require these diagnostic codes to appear at least once in the output.
+    pub fn expect_codes(mut self, codes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.expect_codes = codes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Parse `cargo`'s `--message-format=json` output into diagnostics,
+    /// keeping only `compiler-message` entries (ignoring build-script and
+    /// artifact notifications).
+    pub fn parse_diagnostics(stdout: &str) -> Vec<CompileDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for line in stdout.lines() {
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+            if value.get("reason").and_then(|r| r.as_str()) != Some("compiler-message") {
+                continue;
+            }
+            let Some(message) = value.get("message") else {
+                continue;
+            };
+
+            let level = message
+                .get("level")
+                .and_then(|l| l.as_str())
+                .unwrap_or("unknown")
+                .to_string();
+            let code = message
+                .get("code")
+                .and_then(|c| c.get("code"))
+                .and_then(|c| c.as_str())
+                .map(|s| s.to_string());
+            let text = message
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("")
+                .to_string();
+            // Machine-applicable suggestions live on each child under
+            // `children[].spans[].suggestion_applicability == "MachineApplicable"`,
+            // mirroring what `cargo fix`/rustfix consume.
+            let machine_applicable = message
+                .get("children")
+                .and_then(|c| c.as_array())
+                .map(|children| {
+                    children.iter().any(|child| {
+                        child
+                            .get("spans")
+                            .and_then(|s| s.as_array())
+                            .map(|spans| {
+                                spans.iter().any(|span| {
+                                    span.get("suggestion_applicability").and_then(|a| a.as_str())
+                                        == Some("MachineApplicable")
+                                })
+                            })
+                            .unwrap_or(false)
+                    })
+                })
+                .unwrap_or(false);
+
+            diagnostics.push(CompileDiagnostic {
+                level,
+                code,
+                message: text,
+                machine_applicable,
+            });
+        }
+
+        diagnostics
+    }
+}
+
+#[async_trait]
+impl Metric for CompileCheckMetric {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn evaluate(&self, ctx: &EvalContext) -> Result<MetricResult> {
+        let subcommand = if self.check_only { "check" } else { "build" };
+        let output = Command::new("cargo")
+            .args([subcommand, "--message-format=json"])
+            .current_dir(&ctx.workspace)
+            .output()?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let diagnostics = Self::parse_diagnostics(&stdout);
+
+        let errors: Vec<_> = diagnostics.iter().filter(|d| d.level == "error").collect();
+        let warnings: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.level == "warning")
+            .collect();
+        let needs_rustfix = diagnostics.iter().filter(|d| d.machine_applicable).count();
+
+        if self.require_zero_errors && !errors.is_empty() {
+            return Ok(MetricResult::Fail {
+                reason: format!(
+                    "{} compiler error(s): {}",
+                    errors.len(),
+                    errors
+                        .iter()
+                        .map(|d| d.message.as_str())
+                        .take(3)
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+            });
+        }
+
+        for code in &self.expect_codes {
+            if !diagnostics.iter().any(|d| d.code.as_deref() == Some(code)) {
+                return Ok(MetricResult::Fail {
+                    reason: format!("expected diagnostic code '{}' was not emitted", code),
+                });
+            }
+        }
+
+        if needs_rustfix > 0 {
+            // Some diagnostics still have machine-applicable fixes available
+            // (what `cargo fix` would clean up) - score rather than pass/fail.
+            return Ok(MetricResult::Score {
+                value: (diagnostics.len() - needs_rustfix) as f64,
+                max: diagnostics.len().max(1) as f64,
+            });
+        }
+
+        if warnings.is_empty() {
+            Ok(MetricResult::Pass)
+        } else {
+            // Dock a tenth of a point per warning so a handful of lint
+            // warnings don't silently look identical to a spotless build.
+            let value = (1.0 - 0.1 * warnings.len() as f64).max(0.0);
+            Ok(MetricResult::Score { value, max: 1.0 })
+        }
+    }
+}
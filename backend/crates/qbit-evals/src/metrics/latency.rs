@@ -0,0 +1,48 @@
+//! Latency metric - checks model round-trip time against a ceiling.
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::{EvalContext, Metric, MetricResult};
+
+pub struct LatencyMetric {
+    name: String,
+    max_duration_ms: Option<u64>,
+}
+
+impl LatencyMetric {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            max_duration_ms: None,
+        }
+    }
+
+    pub fn with_max(name: &str, max_duration_ms: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            max_duration_ms: Some(max_duration_ms),
+        }
+    }
+}
+
+#[async_trait]
+impl Metric for LatencyMetric {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn evaluate(&self, ctx: &EvalContext) -> Result<MetricResult> {
+        let duration_ms = ctx.agent_output.duration_ms;
+        match self.max_duration_ms {
+            Some(max) if duration_ms > max => Ok(MetricResult::Fail {
+                reason: format!("Latency {}ms exceeded maximum {}ms", duration_ms, max),
+            }),
+            Some(_) => Ok(MetricResult::Pass),
+            None => Ok(MetricResult::Score {
+                value: duration_ms as f64,
+                max: f64::MAX,
+            }),
+        }
+    }
+}
@@ -27,6 +27,9 @@ pub struct EvalReport {
     pub duration_ms: u64,
     /// Agent output from the run.
     pub agent_output: AgentOutput,
+    /// Set if the scenario was skipped rather than run (e.g. the active
+    /// provider doesn't advertise a capability the scenario requires).
+    pub skip_reason: Option<String>,
 }
 
 impl EvalReport {
@@ -38,9 +41,28 @@ impl EvalReport {
             metrics: Vec::new(),
             duration_ms,
             agent_output,
+            skip_reason: None,
         }
     }
 
+    /// Create a report for a scenario that was skipped without running, e.g.
+    /// because the active provider lacks a capability it requires.
+    pub fn skipped(scenario: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            scenario: scenario.into(),
+            passed: true,
+            metrics: Vec::new(),
+            duration_ms: 0,
+            agent_output: AgentOutput::default(),
+            skip_reason: Some(reason.into()),
+        }
+    }
+
+    /// Whether this report represents a skipped scenario.
+    pub fn is_skipped(&self) -> bool {
+        self.skip_reason.is_some()
+    }
+
     /// Add a metric outcome and update passed status.
     pub fn add_metric(&mut self, name: impl Into<String>, result: MetricResult) {
         let passed = result.passed();
@@ -55,6 +77,15 @@ impl EvalReport {
 
     /// Print a summary to the terminal.
     pub fn print_summary<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        if let Some(reason) = &self.skip_reason {
+            writeln!(
+                w,
+                "\n\x1b[90mSKIP\x1b[0m {} ({})",
+                self.scenario, reason
+            )?;
+            return Ok(());
+        }
+
         let status = if self.passed { "PASS" } else { "FAIL" };
         let status_color = if self.passed { "\x1b[32m" } else { "\x1b[31m" };
         let reset = "\x1b[0m";
@@ -110,6 +141,7 @@ impl EvalReport {
         serde_json::json!({
             "scenario": self.scenario,
             "passed": self.passed,
+            "skip_reason": self.skip_reason,
             "duration_ms": self.duration_ms,
             "metrics": self.metrics.iter().map(|m| {
                 let (status, details) = match &m.result {
@@ -151,34 +183,70 @@ impl EvalSummary {
         self.reports.push(report);
     }
 
-    /// Count of passed scenarios.
+    /// Count of passed scenarios (skipped scenarios don't count as passed).
     pub fn passed_count(&self) -> usize {
-        self.reports.iter().filter(|r| r.passed).count()
+        self.reports
+            .iter()
+            .filter(|r| r.passed && !r.is_skipped())
+            .count()
     }
 
     /// Count of failed scenarios.
     pub fn failed_count(&self) -> usize {
-        self.reports.iter().filter(|r| !r.passed).count()
+        self.reports
+            .iter()
+            .filter(|r| !r.passed && !r.is_skipped())
+            .count()
+    }
+
+    /// Count of skipped scenarios.
+    pub fn skipped_count(&self) -> usize {
+        self.reports.iter().filter(|r| r.is_skipped()).count()
     }
 
-    /// Overall pass rate.
+    /// Overall pass rate, excluding skipped scenarios from the denominator.
     pub fn pass_rate(&self) -> f64 {
-        if self.reports.is_empty() {
+        let considered = self.reports.len() - self.skipped_count();
+        if considered == 0 {
             0.0
         } else {
-            self.passed_count() as f64 / self.reports.len() as f64
+            self.passed_count() as f64 / considered as f64
         }
     }
 
+    /// Print a latency/token-usage comparison table across all reports, so a
+    /// multi-model run (e.g. the OpenAI connectivity suite) can be scanned
+    /// for a model that's silently routing to a slower or more expensive
+    /// backing model. Skipped scenarios are omitted since they have no
+    /// agent output to compare.
+    pub fn print_model_comparison<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        writeln!(w, "\n{:<40} {:>12} {:>10}", "Scenario", "Latency(ms)", "Tokens")?;
+        writeln!(w, "{}", "-".repeat(64))?;
+        for report in self.reports.iter().filter(|r| !r.is_skipped()) {
+            let tokens = report
+                .agent_output
+                .tokens_used
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            writeln!(
+                w,
+                "{:<40} {:>12} {:>10}",
+                report.scenario, report.duration_ms, tokens
+            )?;
+        }
+        Ok(())
+    }
+
     /// Print aggregate summary.
     pub fn print_summary<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
         writeln!(w, "\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━")?;
         writeln!(
             w,
-            "Results: {}/{} passed ({:.0}%)",
+            "Results: {}/{} passed ({:.0}%), {} skipped",
             self.passed_count(),
-            self.reports.len(),
-            self.pass_rate() * 100.0
+            self.reports.len() - self.skipped_count(),
+            self.pass_rate() * 100.0,
+            self.skipped_count()
         )?;
         writeln!(w, "Duration: {}ms", self.total_duration_ms)?;
         writeln!(w, "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━")?;
@@ -9,7 +9,7 @@ use anyhow::Result;
 use tempfile::TempDir;
 
 /// Output captured from an agent run.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct AgentOutput {
     /// Final text response from the agent.
     pub response: String,
@@ -141,13 +141,18 @@ impl EvalRunner {
         // Get embedded testbed content
         let content = get_testbed_content(testbed_name)?;
 
-        // Write files to workspace
+        // Write files to workspace. Annotation directives (`//@ ...`) are
+        // stripped first so their own text can't satisfy the check they
+        // describe (see `scenarios::annotations::strip_annotations`).
         for (relative_path, file_content) in content {
             let full_path = testbed_path.join(&relative_path);
             if let Some(parent) = full_path.parent() {
                 std::fs::create_dir_all(parent)?;
             }
-            std::fs::write(&full_path, file_content)?;
+            std::fs::write(
+                &full_path,
+                crate::scenarios::annotations::strip_annotations(&file_content),
+            )?;
         }
 
         Ok(testbed_path)
@@ -0,0 +1,147 @@
+//! Inline expectation annotations embedded in testbed files.
+//!
+//! Rather than wiring every assertion up in Rust, a testbed file can carry
+//! its own checks as magic comments, in the spirit of compiletest's
+//! `//~ ERROR` markers:
+//!
+//! ```text
+//! //@ expect-contains: fn calculate_total
+//! //@ expect-file-modified
+//! //@ expect-llm-judge: The function should sum the vector and return an i32.
+//! ```
+//!
+//! [`extract_annotated_metrics`] scans a scenario's `testbed_files()` output
+//! for these comments and converts each into the corresponding
+//! [`FileStateMetric`]/[`LlmJudgeMetric`], to be merged with the scenario's
+//! programmatic `metrics()`.
+
+use crate::metrics::{FileStateMetric, LlmJudgeMetric, Metric};
+
+/// Default pass threshold for `//@ expect-llm-judge:` annotations.
+const DEFAULT_JUDGE_THRESHOLD: f64 = 0.7;
+
+/// Parse the `//@ ...` annotations out of a single file's contents and
+/// convert them into metrics scoped to `path`.
+fn extract_file_annotations(path: &str, content: &str) -> Vec<Box<dyn Metric>> {
+    let mut metrics: Vec<Box<dyn Metric>> = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let Some(directive) = line.trim_start().strip_prefix("//@ ") else {
+            continue;
+        };
+        let directive = directive.trim();
+
+        if let Some(pattern) = directive.strip_prefix("expect-contains:") {
+            let pattern = pattern.trim().to_string();
+            metrics.push(Box::new(FileStateMetric::contains(
+                format!("{path}:{}:expect-contains", i + 1),
+                path,
+                pattern,
+            )));
+        } else if directive == "expect-file-modified" {
+            metrics.push(Box::new(FileStateMetric::modified(
+                format!("{path}:{}:expect-file-modified", i + 1),
+                path,
+            )));
+        } else if let Some(pattern) = directive.strip_prefix("expect-matches:") {
+            let pattern = pattern.trim().to_string();
+            metrics.push(Box::new(FileStateMetric::matches(
+                format!("{path}:{}:expect-matches", i + 1),
+                path,
+                pattern,
+            )));
+        } else if let Some(criteria) = directive.strip_prefix("expect-llm-judge:") {
+            let criteria = criteria.trim().to_string();
+            metrics.push(Box::new(LlmJudgeMetric::new(
+                format!("{path}:{}:expect-llm-judge", i + 1),
+                criteria,
+                DEFAULT_JUDGE_THRESHOLD,
+            )));
+        }
+        // Unrecognized `//@ ...` directives are ignored rather than treated
+        // as an error, so new annotation kinds can be added incrementally.
+    }
+
+    metrics
+}
+
+/// Extract all annotation-derived metrics from a scenario's testbed files.
+pub fn extract_annotated_metrics(testbed_files: &[(String, String)]) -> Vec<Box<dyn Metric>> {
+    testbed_files
+        .iter()
+        .flat_map(|(path, content)| extract_file_annotations(path, content))
+        .collect()
+}
+
+/// Merge a scenario's programmatic metrics with the ones derived from
+/// `//@ ...` annotations embedded in its testbed files.
+pub fn merge_with_annotated_metrics(
+    mut metrics: Vec<Box<dyn Metric>>,
+    testbed_files: &[(String, String)],
+) -> Vec<Box<dyn Metric>> {
+    metrics.extend(extract_annotated_metrics(testbed_files));
+    metrics
+}
+
+/// Strip `//@ ...` annotation lines out of a file's content before it's
+/// written to the agent's workspace.
+///
+/// Without this, an annotation like `//@ expect-contains: fn reverse` is
+/// itself text containing the pattern it expects the agent to add, so the
+/// corresponding [`FileStateMetric::contains`] check (which reads the file
+/// back off disk after the run) would pass even if the agent never touched
+/// the file. [`extract_annotated_metrics`] parses annotations from the
+/// original, un-stripped `testbed_files()` output, so stripping them here
+/// only affects what the agent sees and what gets graded.
+pub fn strip_annotations(content: &str) -> String {
+    let mut stripped = content
+        .lines()
+        .filter(|line| line.trim_start().strip_prefix("//@ ").is_none())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if content.ends_with('\n') {
+        stripped.push('\n');
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::EvalContext;
+    use crate::runner::AgentOutput;
+
+    const LIB_RS: &str = "pub struct StringUtils;\n\nimpl StringUtils {\n    // TODO: Add reverse method\n    //@ expect-contains: fn reverse\n}\n";
+
+    #[test]
+    fn test_strip_annotations_removes_directive_line() {
+        let stripped = strip_annotations(LIB_RS);
+        assert!(!stripped.contains("fn reverse"));
+        assert!(!stripped.contains("//@"));
+    }
+
+    #[tokio::test]
+    async fn test_unmodified_file_fails_expect_contains() {
+        // Regression test: the annotation directive's own text contains the
+        // pattern it expects ("expect-contains: fn reverse" contains "fn
+        // reverse"), so if the directive line were left in the workspace a
+        // no-op agent run would pass this check. It must fail instead.
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lib.rs"), strip_annotations(LIB_RS)).unwrap();
+
+        let metrics = extract_file_annotations("lib.rs", LIB_RS);
+        assert_eq!(metrics.len(), 1);
+
+        let ctx = EvalContext {
+            workspace: dir.path().to_path_buf(),
+            agent_output: AgentOutput::default(),
+            prompt: String::new(),
+        };
+
+        let result = metrics[0].evaluate(&ctx).await.unwrap();
+        assert!(
+            !result.passed(),
+            "unmodified file should fail expect-contains: fn reverse"
+        );
+    }
+}
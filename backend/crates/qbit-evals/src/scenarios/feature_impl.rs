@@ -4,7 +4,8 @@
 
 use async_trait::async_trait;
 
-use crate::metrics::{CodeCorrectnessMetric, FileStateMetric, LlmJudgeMetric, Metric};
+use crate::metrics::{CodeCorrectnessMetric, CompileCheckMetric, LlmJudgeMetric, Metric};
+use crate::scenarios::annotations::merge_with_annotated_metrics;
 use crate::scenarios::Scenario;
 
 /// Scenario: Implement a new method on an existing struct.
@@ -30,19 +31,24 @@ impl Scenario for FeatureImplScenario {
     }
 
     fn metrics(&self) -> Vec<Box<dyn Metric>> {
-        vec![
-            Box::new(CodeCorrectnessMetric::cargo_test()),
-            Box::new(FileStateMetric::contains(
-                "has_reverse_method",
-                "src/lib.rs",
-                "fn reverse",
-            )),
-            Box::new(LlmJudgeMetric::new(
-                "implementation_quality",
-                "Implementation should be idiomatic Rust",
-                0.7,
-            )),
-        ]
+        // The `fn reverse` check (reported as `src/lib.rs:16:expect-contains`)
+        // comes from the `//@ expect-contains:` annotation in this testbed's
+        // src/lib.rs rather than being wired up here.
+        merge_with_annotated_metrics(
+            vec![
+                Box::new(CodeCorrectnessMetric::cargo_test()),
+                // Runs `cargo check` and scores rustc's own diagnostics
+                // (warnings, machine-applicable rustfix hints), which
+                // `cargo_test()` only reports as an opaque pass/fail.
+                Box::new(CompileCheckMetric::new("compiles").check_only()),
+                Box::new(LlmJudgeMetric::new(
+                    "implementation_quality",
+                    "Implementation should be idiomatic Rust",
+                    0.7,
+                )),
+            ],
+            &testbed_files(),
+        )
     }
 }
 
@@ -77,6 +83,7 @@ impl StringUtils {
     }
 
     // TODO: Add reverse method
+    //@ expect-contains: fn reverse
 }
 "#
             .to_string(),
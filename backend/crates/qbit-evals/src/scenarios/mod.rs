@@ -5,15 +5,20 @@
 //! - A prompt for the agent
 //! - Metrics to evaluate the result
 
+pub mod annotations;
 pub mod bug_fix;
 pub mod code_understanding;
 pub mod feature_impl;
+pub mod model_scenario;
 pub mod multi_step;
+pub mod openai_models;
+pub mod openai_web_search;
 pub mod refactor;
 
 use anyhow::Result;
 use async_trait::async_trait;
 
+use crate::config::{Capability, EvalProvider};
 use crate::metrics::Metric;
 use crate::outcome::EvalReport;
 use crate::runner::EvalRunner;
@@ -36,6 +41,15 @@ pub trait Scenario: Send + Sync {
     /// Metrics to evaluate the result.
     fn metrics(&self) -> Vec<Box<dyn Metric>>;
 
+    /// Capabilities the active provider must advertise for this scenario to run.
+    ///
+    /// Scenarios that depend on provider-specific features (web search, sub-agents,
+    /// the indexer, extended thinking) should override this. The default is
+    /// empty, meaning the scenario runs against any provider.
+    fn required_capabilities(&self) -> &[Capability] {
+        &[]
+    }
+
     /// Run the scenario and return a report.
     async fn run(&self, runner: &EvalRunner) -> Result<EvalReport> {
         let start = std::time::Instant::now();
@@ -84,3 +98,75 @@ pub fn all_scenarios() -> Vec<Box<dyn Scenario>> {
 pub fn get_scenario(name: &str) -> Option<Box<dyn Scenario>> {
     all_scenarios().into_iter().find(|s| s.name() == name)
 }
+
+/// Get the default scenario set for `provider`, filtered through
+/// [`partition_by_capabilities`] so scenarios the provider can't run (e.g.
+/// web-search is excluded for Z.AI) are skipped rather than attempted and
+/// failed. `explicit = false` is passed, so this can never error (see
+/// `partition_by_capabilities`'s doc comment); skipped scenarios are logged
+/// rather than silently dropped.
+pub fn default_scenarios_for_provider(provider: EvalProvider) -> Vec<Box<dyn Scenario>> {
+    let (runnable, skipped) =
+        partition_by_capabilities(all_scenarios(), provider.capabilities(), false)
+            .unwrap_or_else(|_| (Vec::new(), Vec::new()));
+
+    for report in &skipped {
+        if let Some(reason) = &report.skip_reason {
+            eprintln!("Skipping scenario '{}': {}", report.scenario, reason);
+        }
+    }
+
+    runnable
+}
+
+/// Capability gate applied to a set of scenarios before running them.
+///
+/// Scenarios whose `required_capabilities()` aren't fully covered by
+/// `available` are skipped (producing a `Skipped` report explaining why)
+/// rather than run and scored as a failure. Scenarios named explicitly by
+/// the caller (`explicit = true`, e.g. passed on the command line) are held
+/// to a stricter standard: missing capabilities are a hard error instead of
+/// a skip, since the user asked for that scenario specifically.
+pub fn partition_by_capabilities(
+    scenarios: Vec<Box<dyn Scenario>>,
+    available: &[Capability],
+    explicit: bool,
+) -> Result<(Vec<Box<dyn Scenario>>, Vec<EvalReport>)> {
+    let mut runnable = Vec::new();
+    let mut skipped = Vec::new();
+
+    for scenario in scenarios {
+        let missing: Vec<Capability> = scenario
+            .required_capabilities()
+            .iter()
+            .filter(|cap| !available.contains(cap))
+            .copied()
+            .collect();
+
+        if missing.is_empty() {
+            runnable.push(scenario);
+            continue;
+        }
+
+        let reason = format!(
+            "missing required capabilities: {}",
+            missing
+                .iter()
+                .map(|cap| format!("{:?}", cap))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        if explicit {
+            anyhow::bail!(
+                "scenario '{}' requires capabilities the active provider doesn't support ({})",
+                scenario.name(),
+                reason
+            );
+        }
+
+        skipped.push(EvalReport::skipped(scenario.name(), reason));
+    }
+
+    Ok((runnable, skipped))
+}
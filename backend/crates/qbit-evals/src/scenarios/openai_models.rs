@@ -7,43 +7,100 @@
 //! - Validating API key configuration
 //! - Testing model availability
 //! - Verifying the OpenAI provider integration works end-to-end
+//!
+//! Because many providers (Groq, OpenRouter, Together, Mistral, Fireworks,
+//! Perplexity, DeepInfra, Moonshot, ...) expose the same chat-completions
+//! request/response shape as OpenAI, just at a different base URL,
+//! [`OPENAI_TEST_MODELS`] is keyed by `(provider_label, base_url, model_id,
+//! display_name, capabilities)` rather than just `(model_id, display_name)`.
+//! A `base_url` of `None` means "use the configured/default OpenAI
+//! endpoint"; `Some(url)` overrides it for that entry, so a single
+//! connectivity harness can smoke-test any OpenAI-compatible platform.
+//!
+//! The scenario type and dispatch live in
+//! [`crate::scenarios::model_scenario`]; this file only supplies the model
+//! table and the OpenAI-specific [`ModelExecutor`] implementation.
 
 use async_trait::async_trait;
+use std::path::Path;
 
-use crate::metrics::{Metric, MetricResult};
-use crate::outcome::EvalReport;
-use crate::runner::{AgentOutput, EvalRunner};
+use crate::config::{EvalConfig, EvalProvider};
+use crate::runner::{AgentOutput, VerboseConfig};
+use crate::scenarios::model_scenario::{ModelCapability, ModelExecutor, ModelScenario};
 use crate::scenarios::Scenario;
 
-/// OpenAI models to test for basic connectivity.
-/// These are unique model IDs (not counting reasoning effort variants).
-pub const OPENAI_TEST_MODELS: &[(&str, &str)] = &[
+const TEXT_VISION_TOOLS: &[ModelCapability] = &[
+    ModelCapability::Text,
+    ModelCapability::Vision,
+    ModelCapability::Tools,
+];
+const TEXT_REASONING_TOOLS: &[ModelCapability] = &[
+    ModelCapability::Text,
+    ModelCapability::Reasoning,
+    ModelCapability::Tools,
+];
+const TEXT_TOOLS: &[ModelCapability] = &[ModelCapability::Text, ModelCapability::Tools];
+
+/// Models to test for basic connectivity, as `(provider_label, base_url,
+/// model_id, display_name, capabilities)`. These are unique model IDs (not
+/// counting reasoning effort variants).
+pub const OPENAI_TEST_MODELS: &[(&str, Option<&str>, &str, &str, &[ModelCapability])] = &[
     // GPT-5 series
-    ("gpt-5.2", "GPT 5.2"),
-    ("gpt-5.1", "GPT 5.1"),
-    ("gpt-5", "GPT 5"),
-    ("gpt-5-mini", "GPT 5 Mini"),
-    ("gpt-5-nano", "GPT 5 Nano"),
+    ("openai", None, "gpt-5.2", "GPT 5.2", TEXT_VISION_TOOLS),
+    ("openai", None, "gpt-5.1", "GPT 5.1", TEXT_VISION_TOOLS),
+    ("openai", None, "gpt-5", "GPT 5", TEXT_VISION_TOOLS),
+    ("openai", None, "gpt-5-mini", "GPT 5 Mini", TEXT_VISION_TOOLS),
+    ("openai", None, "gpt-5-nano", "GPT 5 Nano", TEXT_VISION_TOOLS),
     // GPT-4.1 series
-    ("gpt-4.1", "GPT 4.1"),
-    ("gpt-4.1-mini", "GPT 4.1 Mini"),
-    ("gpt-4.1-nano", "GPT 4.1 Nano"),
+    ("openai", None, "gpt-4.1", "GPT 4.1", TEXT_VISION_TOOLS),
+    (
+        "openai",
+        None,
+        "gpt-4.1-mini",
+        "GPT 4.1 Mini",
+        TEXT_VISION_TOOLS,
+    ),
+    (
+        "openai",
+        None,
+        "gpt-4.1-nano",
+        "GPT 4.1 Nano",
+        TEXT_VISION_TOOLS,
+    ),
     // GPT-4o series
-    ("gpt-4o", "GPT 4o"),
-    ("gpt-4o-mini", "GPT 4o Mini"),
-    ("chatgpt-4o-latest", "ChatGPT 4o Latest"),
+    ("openai", None, "gpt-4o", "GPT 4o", TEXT_VISION_TOOLS),
+    ("openai", None, "gpt-4o-mini", "GPT 4o Mini", TEXT_VISION_TOOLS),
+    (
+        "openai",
+        None,
+        "chatgpt-4o-latest",
+        "ChatGPT 4o Latest",
+        TEXT_VISION_TOOLS,
+    ),
     // o-series reasoning models
-    ("o4-mini", "o4 Mini"),
-    ("o3", "o3"),
-    ("o3-mini", "o3 Mini"),
-    ("o1", "o1"),
+    ("openai", None, "o4-mini", "o4 Mini", TEXT_REASONING_TOOLS),
+    ("openai", None, "o3", "o3", TEXT_REASONING_TOOLS),
+    ("openai", None, "o3-mini", "o3 Mini", TEXT_REASONING_TOOLS),
+    ("openai", None, "o1", "o1", TEXT_VISION_TOOLS),
     // Codex models (gpt-5.2-codex* not yet available)
-    ("gpt-5.1-codex", "GPT 5.1 Codex"),
-    ("gpt-5.1-codex-max", "GPT 5.1 Codex Max"),
-    ("codex-mini-latest", "Codex Mini"),
+    ("openai", None, "gpt-5.1-codex", "GPT 5.1 Codex", TEXT_TOOLS),
+    (
+        "openai",
+        None,
+        "gpt-5.1-codex-max",
+        "GPT 5.1 Codex Max",
+        TEXT_TOOLS,
+    ),
+    (
+        "openai",
+        None,
+        "codex-mini-latest",
+        "Codex Mini",
+        TEXT_TOOLS,
+    ),
 ];
 
-/// OpenAI models that don't support the temperature parameter.
+/// Built-in OpenAI models that don't support the temperature parameter.
 const NO_TEMPERATURE_MODELS: &[&str] = &[
     "o1",
     "o1-preview",
@@ -58,233 +115,261 @@ const NO_TEMPERATURE_MODELS: &[&str] = &[
     "codex-mini-latest",
 ];
 
-/// Check if a model supports the temperature parameter.
+/// Check if a built-in model supports the temperature parameter.
 fn supports_temperature(model_id: &str) -> bool {
     !NO_TEMPERATURE_MODELS.contains(&model_id)
 }
 
-/// Simple metric that checks if a response was received.
-#[derive(Default)]
-pub struct ResponseReceivedMetric;
-
-impl ResponseReceivedMetric {
-    pub fn new() -> Self {
-        Self
-    }
-}
-
-#[async_trait]
-impl Metric for ResponseReceivedMetric {
-    fn name(&self) -> &str {
-        "response_received"
-    }
-
-    async fn evaluate(&self, ctx: &crate::metrics::EvalContext) -> anyhow::Result<MetricResult> {
-        // Check that we got a non-empty response
-        let response = &ctx.agent_output.response;
-
-        if response.is_empty() {
-            Ok(MetricResult::Fail {
-                reason: "No response received from model".to_string(),
-            })
-        } else {
-            Ok(MetricResult::Pass)
+/// Parse a `[ai.openai].models` capability tag into a [`ModelCapability`].
+/// Unrecognized tags are ignored (logged to stderr) rather than failing the
+/// whole entry, since new tags may be added before the harness knows them.
+fn parse_capability(tag: &str) -> Option<ModelCapability> {
+    match tag {
+        "text" => Some(ModelCapability::Text),
+        "vision" => Some(ModelCapability::Vision),
+        "reasoning" => Some(ModelCapability::Reasoning),
+        "tools" => Some(ModelCapability::Tools),
+        other => {
+            eprintln!("warning: ignoring unrecognized model capability tag '{other}'");
+            None
         }
     }
 }
 
-/// Scenario for testing a single OpenAI model.
-pub struct OpenAiModelScenario {
-    model_id: String,
-    model_name: String,
+/// A resolved model entry, after merging `[ai.openai].models` over
+/// [`OPENAI_TEST_MODELS`].
+struct ResolvedModel {
+    base_url: Option<String>,
+    id: String,
+    display_name: String,
+    capabilities: Vec<ModelCapability>,
+    max_tokens: Option<u32>,
+    supports_temperature: bool,
 }
 
-impl OpenAiModelScenario {
-    pub fn new(model_id: &str, model_name: &str) -> Self {
-        Self {
-            model_id: model_id.to_string(),
-            model_name: model_name.to_string(),
-        }
-    }
-
-    /// Get the model ID to test.
-    pub fn model_id(&self) -> &str {
-        &self.model_id
-    }
-}
+/// [`ModelExecutor`] for OpenAI, and any OpenAI-compatible API reachable via
+/// a custom base URL.
+pub struct OpenAiExecutor;
 
 #[async_trait]
-impl Scenario for OpenAiModelScenario {
-    fn name(&self) -> &str {
-        // We return a static str, but for dynamic names we need to use Box::leak
-        // This is fine for short-lived scenarios
-        Box::leak(format!("openai-{}", self.model_id).into_boxed_str())
-    }
-
-    fn description(&self) -> &str {
-        Box::leak(
-            format!(
-                "Test {} model configuration with hello world prompt",
-                self.model_name
-            )
-            .into_boxed_str(),
-        )
-    }
-
-    fn testbed(&self) -> &str {
-        "minimal" // Use the minimal testbed (empty workspace)
-    }
-
-    fn prompt(&self) -> &str {
-        "Say hello world. Keep your response brief."
-    }
-
-    fn system_prompt(&self) -> Option<&str> {
-        Some("You are a helpful assistant. Respond briefly and concisely.")
-    }
-
-    fn metrics(&self) -> Vec<Box<dyn Metric>> {
-        vec![Box::new(ResponseReceivedMetric::new())]
-    }
-
-    /// Custom run implementation that uses the specific OpenAI model.
-    async fn run(&self, runner: &EvalRunner) -> anyhow::Result<EvalReport> {
-        use crate::config::{EvalConfig, EvalProvider};
-        use crate::runner::VerboseConfig;
+impl ModelExecutor for OpenAiExecutor {
+    async fn execute(
+        &self,
+        _workspace: &Path,
+        prompt: &str,
+        system_prompt: Option<&str>,
+        verbose_config: &VerboseConfig,
+        config: &EvalConfig,
+        model_id: &str,
+        base_url: Option<&str>,
+        image_base64: Option<&str>,
+        max_tokens: Option<u32>,
+        supports_temperature: bool,
+    ) -> anyhow::Result<AgentOutput> {
+        use rig::client::CompletionClient;
+        use rig::completion::{CompletionModel, CompletionRequest, Message};
+        use rig::message::{DocumentSourceKind, Image, ImageMediaType, Text, UserContent};
+        use rig::one_or_many::OneOrMany;
+        use rig::providers::openai as rig_openai;
+
+        let openai_config = config
+            .openai
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("OpenAI configuration not available"))?;
+
+        let client: rig_openai::Client = match base_url.or(openai_config.api_base.as_deref()) {
+            Some(base_url) => rig_openai::Client::from_url(&openai_config.api_key, base_url)
+                .map_err(|e| anyhow::anyhow!("Failed to create OpenAI client: {}", e))?,
+            None => rig_openai::Client::new(&openai_config.api_key)
+                .map_err(|e| anyhow::anyhow!("Failed to create OpenAI client: {}", e))?,
+        };
+        let model = client.completion_model(model_id);
 
         let start = std::time::Instant::now();
 
-        // Setup minimal testbed
-        let workspace = runner.setup_testbed(self.testbed()).await?;
-
-        // Load OpenAI config
-        let config = EvalConfig::load_for_provider(EvalProvider::OpenAi).await?;
+        // Print the user prompt if verbose
+        if verbose_config.enabled {
+            println!();
+            println!("\x1b[36m━━━ User ({}) ━━━\x1b[0m", model_id);
+            println!("{}", prompt);
+        }
 
-        // Execute with specific model
-        let agent_output = execute_with_openai_model(
-            &workspace,
-            self.prompt(),
-            self.system_prompt(),
-            &VerboseConfig::default(),
-            &config,
-            &self.model_id,
-        )
-        .await?;
+        // Build simple completion request (no tools for hello world/vision test)
+        let mut content = vec![UserContent::Text(Text {
+            text: prompt.to_string(),
+        })];
+        if let Some(image_base64) = image_base64 {
+            content.push(UserContent::Image(Image {
+                data: DocumentSourceKind::Base64(image_base64.to_string()),
+                media_type: Some(ImageMediaType::PNG),
+                detail: None,
+                additional_params: None,
+            }));
+        }
+        let chat_history = vec![Message::User {
+            content: OneOrMany::many(content).unwrap_or_else(|_| {
+                OneOrMany::one(UserContent::Text(Text {
+                    text: prompt.to_string(),
+                }))
+            }),
+        }];
+
+        // Some models (o-series, codex, gpt-5 base) don't support temperature
+        let temperature = if supports_temperature { Some(0.3) } else { None };
+
+        let request = CompletionRequest {
+            preamble: system_prompt.map(|s| s.to_string()),
+            chat_history: OneOrMany::many(chat_history.clone())
+                .unwrap_or_else(|_| OneOrMany::one(chat_history[0].clone())),
+            documents: vec![],
+            tools: vec![], // No tools needed for hello world
+            temperature,
+            max_tokens: Some(max_tokens.unwrap_or(256) as u64),
+            tool_choice: None,
+            additional_params: None,
+        };
 
-        // Create report
-        let mut report = EvalReport::new(
-            self.name(),
-            agent_output.clone(),
-            start.elapsed().as_millis() as u64,
-        );
+        let response = model.completion(request).await?;
 
-        // Evaluate metrics
-        let ctx = crate::metrics::EvalContext {
-            workspace,
-            agent_output,
-            prompt: self.prompt().to_string(),
-        };
+        // Extract text response
+        let mut response_text = String::new();
+        for content in response.choice.iter() {
+            if let rig::completion::AssistantContent::Text(text) = content {
+                response_text.push_str(&text.text);
+            }
+        }
 
-        for metric in self.metrics() {
-            let result = metric.evaluate(&ctx).await?;
-            report.add_metric(metric.name(), result);
+        if verbose_config.enabled {
+            println!("\n\x1b[33m━━━ Agent ━━━\x1b[0m");
+            println!("{}", response_text);
         }
 
-        Ok(report)
+        Ok(AgentOutput {
+            response: response_text.trim().to_string(),
+            tool_calls: vec![],
+            files_modified: vec![],
+            duration_ms: start.elapsed().as_millis() as u64,
+            tokens_used: Some(response.usage.total_tokens as u32),
+        })
     }
 }
 
-/// Execute a prompt with a specific OpenAI model.
-async fn execute_with_openai_model(
-    _workspace: &std::path::Path,
-    prompt: &str,
-    system_prompt: Option<&str>,
-    verbose_config: &crate::runner::VerboseConfig,
-    config: &crate::config::EvalConfig,
-    model_id: &str,
-) -> anyhow::Result<AgentOutput> {
-    use rig::client::CompletionClient;
-    use rig::completion::{CompletionModel, CompletionRequest, Message};
-    use rig::message::{Text, UserContent};
-    use rig::one_or_many::OneOrMany;
-    use rig::providers::openai as rig_openai;
-
-    let openai_config = config
-        .openai
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("OpenAI configuration not available"))?;
-
-    let client: rig_openai::Client = rig_openai::Client::new(&openai_config.api_key)
-        .map_err(|e| anyhow::anyhow!("Failed to create OpenAI client: {}", e))?;
-    let model = client.completion_model(model_id);
-
-    let start = std::time::Instant::now();
-
-    // Print the user prompt if verbose
-    if verbose_config.enabled {
-        println!();
-        println!("\x1b[36m━━━ User ({}) ━━━\x1b[0m", model_id);
-        println!("{}", prompt);
+/// Merge `[ai.openai].models` over [`OPENAI_TEST_MODELS`]: entries whose
+/// `id` matches a built-in model override its `display_name`/`max_tokens`/
+/// `supports_temperature`/`capabilities`; other entries are appended.
+fn merge_models(configured: &[crate::config::EvalModelConfig]) -> Vec<ResolvedModel> {
+    let mut resolved: Vec<ResolvedModel> = OPENAI_TEST_MODELS
+        .iter()
+        .map(|(_provider, base_url, id, name, capabilities)| ResolvedModel {
+            base_url: base_url.map(str::to_string),
+            id: id.to_string(),
+            display_name: name.to_string(),
+            capabilities: capabilities.to_vec(),
+            max_tokens: None,
+            supports_temperature: supports_temperature(id),
+        })
+        .collect();
+
+    for model in configured {
+        let capabilities: Vec<ModelCapability> = model
+            .capabilities
+            .iter()
+            .filter_map(|tag| parse_capability(tag))
+            .collect();
+
+        if let Some(existing) = resolved.iter_mut().find(|m| m.id == model.id) {
+            existing.display_name = model.display_name.clone();
+            existing.max_tokens = model.max_tokens;
+            existing.supports_temperature = model.supports_temperature;
+            if !capabilities.is_empty() {
+                existing.capabilities = capabilities;
+            }
+        } else {
+            resolved.push(ResolvedModel {
+                base_url: None,
+                id: model.id.clone(),
+                display_name: model.display_name.clone(),
+                capabilities,
+                max_tokens: model.max_tokens,
+                supports_temperature: model.supports_temperature,
+            });
+        }
     }
 
-    // Build simple completion request (no tools for hello world test)
-    let chat_history = vec![Message::User {
-        content: OneOrMany::one(UserContent::Text(Text {
-            text: prompt.to_string(),
-        })),
-    }];
-
-    // Some models (o-series, codex, gpt-5 base) don't support temperature
-    let temperature = if supports_temperature(model_id) {
-        Some(0.3)
-    } else {
-        None
-    };
-
-    let request = CompletionRequest {
-        preamble: system_prompt.map(|s| s.to_string()),
-        chat_history: OneOrMany::many(chat_history.clone())
-            .unwrap_or_else(|_| OneOrMany::one(chat_history[0].clone())),
-        documents: vec![],
-        tools: vec![], // No tools needed for hello world
-        temperature,
-        max_tokens: Some(256),
-        tool_choice: None,
-        additional_params: None,
-    };
-
-    let response = model.completion(request).await?;
+    resolved
+}
 
-    // Extract text response
-    let mut response_text = String::new();
-    for content in response.choice.iter() {
-        if let rig::completion::AssistantContent::Text(text) = content {
-            response_text.push_str(&text.text);
+/// Get all OpenAI-compatible model scenarios. Vision-capable models get an
+/// additional image-description scenario alongside the text one.
+///
+/// Loads `[ai.openai].models` from settings and merges it over the built-in
+/// [`OPENAI_TEST_MODELS`] table (see [`merge_models`]), so custom gateways,
+/// fine-tunes, or preview models can be validated without a crate rebuild.
+/// Listing scenarios shouldn't hard-require a configured API key, so a
+/// failure to load config falls back to just the built-in table.
+pub async fn all_openai_model_scenarios() -> Vec<Box<dyn Scenario>> {
+    let configured = EvalConfig::load_for_provider(EvalProvider::OpenAi)
+        .await
+        .ok()
+        .and_then(|config| config.openai)
+        .map(|openai| openai.models)
+        .unwrap_or_default();
+
+    let mut scenarios: Vec<Box<dyn Scenario>> = Vec::new();
+    for model in merge_models(&configured) {
+        scenarios.push(Box::new(ModelScenario::new(
+            EvalProvider::OpenAi,
+            model.base_url.as_deref(),
+            &model.id,
+            &model.display_name,
+            model.max_tokens,
+            model.supports_temperature,
+        )));
+        if model.capabilities.contains(&ModelCapability::Vision) {
+            scenarios.push(Box::new(ModelScenario::vision(
+                EvalProvider::OpenAi,
+                model.base_url.as_deref(),
+                &model.id,
+                &model.display_name,
+                model.max_tokens,
+                model.supports_temperature,
+            )));
         }
     }
-
-    if verbose_config.enabled {
-        println!("\n\x1b[33m━━━ Agent ━━━\x1b[0m");
-        println!("{}", response_text);
-    }
-
-    Ok(AgentOutput {
-        response: response_text.trim().to_string(),
-        tool_calls: vec![],
-        files_modified: vec![],
-        duration_ms: start.elapsed().as_millis() as u64,
-        tokens_used: Some(response.usage.total_tokens as u32),
-    })
+    scenarios
 }
 
-/// Get all OpenAI model scenarios.
-pub fn all_openai_model_scenarios() -> Vec<Box<dyn Scenario>> {
+/// List models available for connectivity testing, as `(model_id,
+/// display_name)`.
+///
+/// This only reports the built-in [`OPENAI_TEST_MODELS`] table: unlike
+/// [`all_openai_model_scenarios`], it's synchronous (so the CLI can list
+/// models without an async runtime) and can't load `[ai.openai].models`
+/// from settings, which requires awaiting [`EvalConfig::load_for_provider`].
+pub fn list_openai_models() -> Vec<(String, String)> {
     OPENAI_TEST_MODELS
         .iter()
-        .map(|(id, name)| Box::new(OpenAiModelScenario::new(id, name)) as Box<dyn Scenario>)
+        .map(|(_provider, _base_url, id, name, _capabilities)| (id.to_string(), name.to_string()))
         .collect()
 }
 
+/// Get all OpenAI model connectivity scenarios (an alias for
+/// [`all_openai_model_scenarios`] under the name the CLI's `--openai-models`
+/// flag uses).
+pub async fn openai_model_scenarios() -> Vec<Box<dyn Scenario>> {
+    all_openai_model_scenarios().await
+}
+
+/// Find the text connectivity scenario for a single model id, if it's in
+/// the merged built-in/configured model set.
+pub async fn get_openai_model_scenario(model_id: &str) -> Option<Box<dyn Scenario>> {
+    let target = format!("{}-{}", EvalProvider::OpenAi, model_id);
+    all_openai_model_scenarios()
+        .await
+        .into_iter()
+        .find(|s| s.name() == target)
+}
+
 /// Testbed files for openai-models scenarios (minimal/empty).
 pub fn testbed_files() -> Vec<(String, String)> {
     // Use the same minimal testbed as web_search
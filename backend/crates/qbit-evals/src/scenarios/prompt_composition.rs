@@ -8,7 +8,8 @@
 
 use async_trait::async_trait;
 
-use crate::metrics::{FileStateMetric, LlmJudgeMetric, Metric};
+use crate::config::Capability;
+use crate::metrics::{CompileCheckMetric, FileStateMetric, LlmJudgeMetric, Metric};
 use crate::scenarios::Scenario;
 
 // =============================================================================
@@ -131,6 +132,7 @@ impl Scenario for CodingConventionsScenario {
                 "src/lib.rs",
                 "///",
             )),
+            Box::new(CompileCheckMetric::new("compiles").check_only()),
             Box::new(
                 LlmJudgeMetric::new(
                     "follows_conventions",
@@ -378,6 +380,10 @@ impl Scenario for SubAgentAwarenessScenario {
         Some(SUB_AGENT_AWARE_SYSTEM_PROMPT)
     }
 
+    fn required_capabilities(&self) -> &[Capability] {
+        &[Capability::SubAgents]
+    }
+
     fn metrics(&self) -> Vec<Box<dyn Metric>> {
         vec![
             Box::new(LlmJudgeMetric::new(
@@ -444,6 +450,10 @@ impl Scenario for ProviderContextScenario {
         Some(PROVIDER_CONTEXT_SYSTEM_PROMPT)
     }
 
+    fn required_capabilities(&self) -> &[Capability] {
+        &[Capability::WebSearch, Capability::ExtendedThinking]
+    }
+
     fn metrics(&self) -> Vec<Box<dyn Metric>> {
         vec![
             Box::new(LlmJudgeMetric::new(
@@ -507,6 +517,7 @@ impl Scenario for SpecificInstructionsScenario {
 
     fn metrics(&self) -> Vec<Box<dyn Metric>> {
         vec![
+            Box::new(CompileCheckMetric::new("compiles").check_only()),
             Box::new(
                 LlmJudgeMetric::new(
                     "follows_naming_convention",
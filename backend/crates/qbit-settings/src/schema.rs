@@ -398,6 +398,37 @@ pub struct OpenAiSettings {
     /// - "high": Better results, but slower and more expensive
     #[serde(default = "default_web_search_context_size")]
     pub web_search_context_size: String,
+
+    /// Extra models to validate in connectivity evals (custom gateways,
+    /// fine-tunes, or preview models not in the eval harness's built-in
+    /// list). Entries whose `id` matches a built-in model override it;
+    /// other entries are tested in addition to the built-in list.
+    #[serde(default)]
+    pub models: Vec<OpenAiEvalModelSetting>,
+}
+
+/// A single model entry under `[ai.openai].models`, letting users validate
+/// custom gateways or preview models in connectivity evals without a
+/// crate rebuild.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OpenAiEvalModelSetting {
+    /// Model ID to pass to the API (e.g. "gpt-5.3-preview").
+    pub id: String,
+
+    /// Human-readable label used in eval reports.
+    pub display_name: String,
+
+    /// Maximum tokens to request. Overrides the eval harness's default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+
+    /// Whether this model accepts the `temperature` parameter.
+    #[serde(default = "default_true")]
+    pub supports_temperature: bool,
+
+    /// Capability tags: "text", "vision", "reasoning", "tools".
+    #[serde(default)]
+    pub capabilities: Vec<String>,
 }
 
 /// Ollama local LLM settings.
@@ -995,6 +1026,7 @@ impl Default for OpenAiSettings {
             show_in_selector: true,
             enable_web_search: false,
             web_search_context_size: "medium".to_string(),
+            models: Vec::new(),
         }
     }
 }
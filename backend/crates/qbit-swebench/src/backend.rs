@@ -0,0 +1,403 @@
+//! Pluggable execution backends for running SWE-bench tests.
+//!
+//! An [`ExecutionBackend`] abstracts over *how* an instance's tests get
+//! executed: in an isolated Docker container ([`DockerBackend`], today's
+//! default) or directly on the host against a fresh git checkout
+//! ([`LocalBackend`], for machines without Docker). Callers drive either
+//! implementation through the same `setup` -> `apply_patch` -> `run_command`
+//! -> `teardown` lifecycle, which keeps the Docker-specific container
+//! bookkeeping out of the evaluator and makes it mockable behind the trait.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::docker::{parse_test_log, DockerExecutor};
+use crate::repo::RepoManager;
+use crate::types::{ResourceUsage, SWEBenchInstance, TestExecutionResult};
+
+/// Default timeout for a command run by [`LocalBackend`].
+const DEFAULT_COMMAND_TIMEOUT_SECS: u64 = 600;
+
+/// Errors an [`ExecutionBackend`] can fail with.
+///
+/// Kept distinct from the stringly-typed `anyhow::Error` used elsewhere in
+/// this crate so callers can branch on *why* a backend failed (e.g. retry on
+/// `Timeout`, but give up on `PatchApply`).
+#[derive(Debug, Error)]
+pub enum BackendError {
+    /// Could not obtain a runnable environment for this instance (image pull
+    /// failure, or no matching image in `docker_image_alternatives()`).
+    #[error("failed to prepare execution environment: {0}")]
+    ImagePull(String),
+
+    /// The given patch didn't apply cleanly against the checked-out source.
+    #[error("failed to apply patch: {0}")]
+    PatchApply(String),
+
+    /// The test command itself could not be started.
+    #[error("failed to spawn test command: {0}")]
+    CommandSpawn(String),
+
+    /// Execution ran longer than the configured timeout.
+    #[error("execution timed out: {0}")]
+    Timeout(String),
+
+    /// Anything else, propagated from lower-level crates (`git2`, `bollard`).
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+/// Result type for [`ExecutionBackend`] operations.
+pub type Result<T> = std::result::Result<T, BackendError>;
+
+/// A backend capable of preparing an environment for a SWE-bench instance,
+/// applying patches to it, and running commands inside it.
+///
+/// Implementations are used for exactly one instance at a time: call
+/// [`setup`](ExecutionBackend::setup), optionally
+/// [`apply_patch`](ExecutionBackend::apply_patch), then one or more
+/// [`run_command`](ExecutionBackend::run_command)s, then
+/// [`teardown`](ExecutionBackend::teardown).
+#[async_trait]
+pub trait ExecutionBackend: Send + Sync {
+    /// Prepare the environment for `instance` (pull an image, or clone and
+    /// check out `base_commit`).
+    async fn setup(&mut self, instance: &SWEBenchInstance) -> Result<()>;
+
+    /// Apply a unified diff to the checked-out source.
+    async fn apply_patch(&mut self, patch: &str) -> Result<()>;
+
+    /// Run a shell command in the prepared environment, parsing its output
+    /// against the current instance's FAIL_TO_PASS/PASS_TO_PASS lists.
+    async fn run_command(&mut self, command: &str) -> Result<TestExecutionResult>;
+
+    /// Release any resources `setup` created (container, temp checkout).
+    async fn teardown(&mut self) -> Result<()>;
+}
+
+/// Runs tests in a Docker container using Epoch AI's benchmark images - the
+/// behavior `DockerExecutor::run_tests` already implemented, now behind the
+/// `ExecutionBackend` trait.
+pub struct DockerBackend {
+    executor: DockerExecutor,
+    repo_manager: RepoManager,
+    workspace: Option<PathBuf>,
+    instance: Option<SWEBenchInstance>,
+}
+
+impl DockerBackend {
+    /// Create a new Docker backend.
+    pub fn new() -> anyhow::Result<Self> {
+        Ok(Self {
+            executor: DockerExecutor::new()?,
+            repo_manager: RepoManager::new()?,
+            workspace: None,
+            instance: None,
+        })
+    }
+
+    /// Whether Docker itself is reachable on this machine.
+    pub async fn is_available(&self) -> bool {
+        self.executor.is_available().await
+    }
+
+    /// Build a backend already attached to an existing, already-populated
+    /// workspace, skipping the fresh clone [`setup`](ExecutionBackend::setup)
+    /// would otherwise perform.
+    ///
+    /// For callers that only want [`run_command`](ExecutionBackend::run_command)
+    /// against a workspace a previous run already checked out and (usually)
+    /// patched - e.g. re-running tests on an existing checkout, or grading a
+    /// workspace an agent already edited in place.
+    pub fn attached(instance: SWEBenchInstance, workspace: PathBuf) -> anyhow::Result<Self> {
+        Ok(Self {
+            executor: DockerExecutor::new()?,
+            repo_manager: RepoManager::new()?,
+            workspace: Some(workspace),
+            instance: Some(instance),
+        })
+    }
+}
+
+#[async_trait]
+impl ExecutionBackend for DockerBackend {
+    async fn setup(&mut self, instance: &SWEBenchInstance) -> Result<()> {
+        if !self.executor.is_available().await {
+            return Err(BackendError::ImagePull("Docker is not available".to_string()));
+        }
+
+        if !self.executor.image_exists(instance).await
+            && !self.executor.pull_image(instance).await.map_err(|e| {
+                let msg = e.to_string();
+                if msg.contains("timed out") {
+                    BackendError::Timeout(msg)
+                } else {
+                    BackendError::ImagePull(msg)
+                }
+            })?
+        {
+            return Err(BackendError::ImagePull(format!(
+                "no image available for {} (tried: {:?})",
+                instance.instance_id,
+                instance.docker_image_alternatives()
+            )));
+        }
+
+        let workspace_dir =
+            std::env::temp_dir().join(format!("qbit-swebench-docker-{}", uuid::Uuid::new_v4()));
+        self.repo_manager.setup_workspace(instance, &workspace_dir)?;
+        self.workspace = Some(workspace_dir);
+        self.instance = Some(instance.clone());
+        Ok(())
+    }
+
+    async fn apply_patch(&mut self, patch: &str) -> Result<()> {
+        let workspace = self
+            .workspace
+            .as_ref()
+            .ok_or_else(|| BackendError::PatchApply("setup() was not called".to_string()))?;
+        self.repo_manager
+            .apply_patch(&workspace.join("repo"), patch)
+            .map_err(|e| BackendError::PatchApply(e.to_string()))
+    }
+
+    async fn run_command(&mut self, _command: &str) -> Result<TestExecutionResult> {
+        // `DockerExecutor::run_tests` builds its own per-repo test command
+        // (including applying `instance.test_patch` inside the container), so
+        // unlike `LocalBackend`, the caller-supplied command string isn't run
+        // verbatim here - the instance itself determines what gets executed.
+        let workspace = self
+            .workspace
+            .as_ref()
+            .ok_or_else(|| BackendError::CommandSpawn("setup() was not called".to_string()))?;
+        let instance = self
+            .instance
+            .as_ref()
+            .ok_or_else(|| BackendError::CommandSpawn("setup() was not called".to_string()))?;
+
+        self.executor.run_tests(instance, workspace).await.map_err(|e| {
+            let msg = e.to_string();
+            if msg.contains("IMAGE_NOT_AVAILABLE") {
+                BackendError::ImagePull(msg)
+            } else {
+                BackendError::Other(e)
+            }
+        })
+    }
+
+    async fn teardown(&mut self) -> Result<()> {
+        if let Some(workspace) = self.workspace.take() {
+            self.repo_manager.cleanup_workspace(&workspace)?;
+        }
+        self.instance = None;
+        Ok(())
+    }
+}
+
+/// Runs tests directly on the host against a fresh git checkout - no Docker
+/// required. Assumes the host already has whatever interpreter/virtualenv
+/// the target repository's `test_command()` expects on `PATH`.
+pub struct LocalBackend {
+    repo_manager: RepoManager,
+    workspace: Option<PathBuf>,
+    instance: Option<SWEBenchInstance>,
+}
+
+impl LocalBackend {
+    /// Create a new local backend.
+    pub fn new() -> anyhow::Result<Self> {
+        Ok(Self {
+            repo_manager: RepoManager::new()?,
+            workspace: None,
+            instance: None,
+        })
+    }
+}
+
+#[async_trait]
+impl ExecutionBackend for LocalBackend {
+    async fn setup(&mut self, instance: &SWEBenchInstance) -> Result<()> {
+        let workspace_dir =
+            std::env::temp_dir().join(format!("qbit-swebench-local-{}", uuid::Uuid::new_v4()));
+        let repo_path = self.repo_manager.setup_workspace(instance, &workspace_dir)?;
+        self.workspace = Some(repo_path);
+        self.instance = Some(instance.clone());
+        Ok(())
+    }
+
+    async fn apply_patch(&mut self, patch: &str) -> Result<()> {
+        let repo_path = self
+            .workspace
+            .as_ref()
+            .ok_or_else(|| BackendError::PatchApply("setup() was not called".to_string()))?;
+        self.repo_manager
+            .apply_patch(repo_path, patch)
+            .map_err(|e| BackendError::PatchApply(e.to_string()))
+    }
+
+    async fn run_command(&mut self, command: &str) -> Result<TestExecutionResult> {
+        let repo_path = self
+            .workspace
+            .as_ref()
+            .ok_or_else(|| BackendError::CommandSpawn("setup() was not called".to_string()))?
+            .clone();
+        let instance = self
+            .instance
+            .as_ref()
+            .ok_or_else(|| BackendError::CommandSpawn("setup() was not called".to_string()))?;
+
+        let start = Instant::now();
+        let command = command.to_string();
+        let (output, peak_rss_bytes, cpu_seconds) = tokio::time::timeout(
+            Duration::from_secs(DEFAULT_COMMAND_TIMEOUT_SECS),
+            tokio::task::spawn_blocking(move || {
+                let mut child = std::process::Command::new("bash")
+                    .arg("-c")
+                    .arg(&command)
+                    .current_dir(&repo_path)
+                    .stdout(std::process::Stdio::piped())
+                    .stderr(std::process::Stdio::piped())
+                    .spawn()?;
+
+                let pid = child.id();
+                let mut peak_rss_bytes = 0u64;
+                let mut cpu_seconds = 0.0f64;
+                loop {
+                    if let Some((rss, cpu)) = sample_proc_usage(pid) {
+                        peak_rss_bytes = peak_rss_bytes.max(rss);
+                        cpu_seconds = cpu;
+                    }
+                    if child.try_wait()?.is_some() {
+                        break;
+                    }
+                    std::thread::sleep(Duration::from_millis(200));
+                }
+
+                let output = child.wait_with_output()?;
+                Ok::<_, std::io::Error>((output, peak_rss_bytes, cpu_seconds))
+            }),
+        )
+        .await
+        .map_err(|_| {
+            BackendError::Timeout(format!(
+                "command did not finish within {}s",
+                DEFAULT_COMMAND_TIMEOUT_SECS
+            ))
+        })?
+        .map_err(|e| BackendError::CommandSpawn(e.to_string()))?
+        .map_err(|e| BackendError::CommandSpawn(e.to_string()))?;
+
+        let wall_ms = start.elapsed().as_millis() as u64;
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+        let fail_to_pass_names = instance.fail_to_pass_tests();
+        let expected: Vec<String> = fail_to_pass_names
+            .iter()
+            .cloned()
+            .chain(instance.pass_to_pass_tests())
+            .collect();
+
+        let (fail_to_pass_results, pass_to_pass_results) =
+            parse_test_log(instance.test_runner(), &stdout, &stderr, &expected)
+                .into_iter()
+                .partition(|result| fail_to_pass_names.contains(&result.name));
+
+        Ok(TestExecutionResult {
+            execution_success: output.status.success(),
+            exit_code: output.status.code().unwrap_or(-1),
+            stdout,
+            stderr,
+            fail_to_pass_results,
+            pass_to_pass_results,
+            duration_ms: wall_ms,
+            resource_usage: Some(ResourceUsage {
+                peak_rss_bytes,
+                cpu_seconds,
+                wall_ms,
+            }),
+        })
+    }
+
+    async fn teardown(&mut self) -> Result<()> {
+        if let Some(repo_path) = self.workspace.take() {
+            if let Some(workspace_dir) = repo_path.parent() {
+                self.repo_manager.cleanup_workspace(workspace_dir)?;
+            }
+        }
+        self.instance = None;
+        Ok(())
+    }
+}
+
+/// Best-effort `/proc/<pid>` sample of peak RSS (bytes) and total CPU time
+/// (seconds) consumed by `pid` so far.
+///
+/// Linux-only, and returns `None` if the process has already exited by the
+/// time we read `/proc` - callers should treat a miss as "no new sample",
+/// not as an error, since they're polling a still-running process.
+fn sample_proc_usage(pid: u32) -> Option<(u64, f64)> {
+    // Standard on Linux; there's no portable way to read sysconf(_SC_CLK_TCK)
+    // without a libc dependency, and 100 is universal on modern kernels.
+    const CLOCK_TICKS_PER_SEC: f64 = 100.0;
+
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let peak_rss_bytes = status
+        .lines()
+        .find(|line| line.starts_with("VmHWM:"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|kb| kb.parse::<u64>().ok())
+        .map(|kb| kb * 1024)?;
+
+    // `/proc/<pid>/stat` is space-separated, but field 2 (comm) can itself
+    // contain spaces inside parentheses - split after the last ')' to skip it.
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    let after_comm = stat.rsplit_once(')')?.1;
+    let fields: Vec<&str> = after_comm.split_whitespace().collect();
+    // utime/stime are overall fields 14/15; relative to `after_comm` (which
+    // starts at field 3) that's indices 11/12.
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+
+    Some((peak_rss_bytes, (utime + stime) as f64 / CLOCK_TICKS_PER_SEC))
+}
+
+/// Select an `ExecutionBackend` at runtime: prefer Docker (today's default
+/// behavior), falling back to the local backend when Docker isn't usable on
+/// this machine.
+pub async fn select_backend() -> anyhow::Result<Box<dyn ExecutionBackend>> {
+    if let Ok(docker) = DockerBackend::new() {
+        if docker.is_available().await {
+            return Ok(Box::new(docker));
+        }
+    }
+    Ok(Box::new(LocalBackend::new()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_error_messages() {
+        assert_eq!(
+            BackendError::ImagePull("no image".to_string()).to_string(),
+            "failed to prepare execution environment: no image"
+        );
+        assert_eq!(
+            BackendError::PatchApply("conflict".to_string()).to_string(),
+            "failed to apply patch: conflict"
+        );
+        assert_eq!(
+            BackendError::CommandSpawn("not found".to_string()).to_string(),
+            "failed to spawn test command: not found"
+        );
+        assert_eq!(
+            BackendError::Timeout("600s".to_string()).to_string(),
+            "execution timed out: 600s"
+        );
+    }
+}
@@ -43,32 +43,40 @@
 //! DatasetLoader     - Downloads/caches SWE-bench Lite from HuggingFace
 //! RepoManager       - Clones repositories, manages worktrees
 //! DockerExecutor    - Runs tests in isolated containers
+//! ExecutionBackend  - Pluggable Docker/local test execution (see `backend`)
 //! SWEBenchScenario  - Implements the Scenario trait for evaluation
 //! ```
 
+pub mod backend;
 pub mod docker;
 pub mod harness;
 pub mod loader;
 pub mod metric;
 pub mod repo;
+pub mod run;
 pub mod scenario;
 pub mod tools;
 pub mod types;
 
-pub use docker::DockerExecutor;
+pub use backend::{select_backend, BackendError, DockerBackend, ExecutionBackend, LocalBackend};
+pub use docker::{parse_test_log, DockerExecutor};
 pub use harness::{
     is_swebench_available, run_fallback_evaluation, run_official_harness, HarnessResult,
 };
 pub use loader::{parse_instance_filter, DatasetLoader, InstanceFilter};
 pub use metric::{FailToPassMetric, PassToPassMetric, SWEBenchTestMetric};
 pub use repo::RepoManager;
+pub use run::{RepoBreakdown, RunResourceUsage, SWEBenchRun};
 pub use scenario::SWEBenchScenario;
 pub use tools::{
     clear_active_container, execute_swebench_test_tool, get_active_container, get_active_context,
     get_swebench_test_tool_definition, is_swebench_tool, set_active_container, set_active_context,
     SWEBenchContext,
 };
-pub use types::{SWEBenchInstance, SWEBenchResult, TestExecutionResult, TestResult, TestRunner};
+pub use types::{
+    ResourceCeiling, ResourceUsage, SWEBenchInstance, SWEBenchResult, TestExecutionResult,
+    TestResult, TestRunner,
+};
 
 use anyhow::Result;
 use qbit_evals::scenarios::Scenario;
@@ -189,14 +197,15 @@ pub async fn run_tests_only(
         instance.pass_to_pass_tests().len()
     );
 
-    // Run Docker tests
-    let docker = DockerExecutor::new()?;
+    // Run tests through the `ExecutionBackend` abstraction, attached to the
+    // existing workspace rather than `setup()`'s fresh clone.
+    let mut backend = DockerBackend::attached(instance.clone(), workspace_dir.to_path_buf())?;
 
-    if !docker.is_available().await {
+    if !backend.is_available().await {
         anyhow::bail!("Docker is not available. Please ensure Docker is running.");
     }
 
-    let test_result = docker.run_tests(&instance, workspace_dir).await?;
+    let test_result = backend.run_command("").await?;
 
     info!(
         "Test results for {}: FAIL_TO_PASS={}/{}, PASS_TO_PASS={}/{}",
@@ -271,6 +271,7 @@ mod tests {
             ],
             pass_to_pass_results: vec![],
             duration_ms: 1000,
+            resource_usage: None,
         };
 
         let metric = FailToPassMetric::new().with_result(result);
@@ -303,6 +304,7 @@ mod tests {
             ],
             pass_to_pass_results: vec![],
             duration_ms: 1000,
+            resource_usage: None,
         };
 
         let metric = FailToPassMetric::new().with_result(result);
@@ -341,6 +343,7 @@ mod tests {
                 },
             ],
             duration_ms: 1000,
+            resource_usage: None,
         };
 
         let metric = PassToPassMetric::new().with_result(result);
@@ -370,6 +373,7 @@ mod tests {
                 duration_ms: None,
             }],
             duration_ms: 1000,
+            resource_usage: None,
         };
 
         let metric = SWEBenchTestMetric::new().with_result(result);
@@ -0,0 +1,353 @@
+//! Run-level aggregation across many SWE-bench instance evaluations.
+//!
+//! `SWEBenchResult` describes the outcome of a single instance; `SWEBenchRun`
+//! collects many of them into a reproducible report: the overall resolve
+//! rate, a per-repo breakdown, and two serializations - the official
+//! `predictions.jsonl` shape consumable by the upstream SWE-bench harness,
+//! and a human-readable summary grouped into solved/partial/failed/error
+//! tiers.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::types::{ResourceUsage, SWEBenchResult};
+
+/// One instance's contribution to a `SWEBenchRun`.
+#[derive(Debug, Clone)]
+struct RunEntry {
+    instance_id: String,
+    result: SWEBenchResult,
+    model_patch: String,
+    resource_usage: Option<ResourceUsage>,
+}
+
+/// Aggregate resource cost across every recorded instance that reported
+/// [`ResourceUsage`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunResourceUsage {
+    /// Highest single-instance peak RSS observed, in bytes.
+    pub max_peak_rss_bytes: u64,
+    /// Sum of per-instance CPU time, in seconds.
+    pub total_cpu_seconds: f64,
+    /// Sum of per-instance wall-clock time, in milliseconds.
+    pub total_wall_ms: u64,
+    /// Number of instances that reported resource usage.
+    pub sample_count: usize,
+}
+
+/// Resolve/solved counts for a single repository owner within a run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RepoBreakdown {
+    /// Instances solved for this repo owner.
+    pub solved: usize,
+    /// Total instances evaluated for this repo owner.
+    pub total: usize,
+}
+
+/// One line of the official SWE-bench `predictions.jsonl` format.
+#[derive(Debug, Serialize)]
+struct Prediction<'a> {
+    instance_id: &'a str,
+    model_name_or_path: &'a str,
+    model_patch: &'a str,
+}
+
+/// Aggregates per-instance `SWEBenchResult`s into a run-level report.
+///
+/// Consume results as they come in with [`add`](SWEBenchRun::add), then ask
+/// for whichever view you need: the raw stats, the official predictions
+/// export, or the human-readable summary.
+#[derive(Debug, Clone, Default)]
+pub struct SWEBenchRun {
+    entries: Vec<RunEntry>,
+}
+
+impl SWEBenchRun {
+    /// Create an empty run.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one instance's result.
+    ///
+    /// `model_patch` is the diff the agent produced for this instance (empty
+    /// if none was produced, e.g. the instance errored before a patch could
+    /// be generated) - it isn't used by the aggregate stats, only by
+    /// [`to_predictions_jsonl`](SWEBenchRun::to_predictions_jsonl).
+    ///
+    /// `resource_usage` is whatever the execution backend captured for this
+    /// instance (see [`TestExecutionResult::resource_usage`](crate::types::TestExecutionResult)),
+    /// or `None` if the backend couldn't observe it - it feeds
+    /// [`resource_usage`](SWEBenchRun::resource_usage) only.
+    pub fn add(
+        &mut self,
+        instance_id: impl Into<String>,
+        result: SWEBenchResult,
+        model_patch: impl Into<String>,
+        resource_usage: Option<ResourceUsage>,
+    ) {
+        self.entries.push(RunEntry {
+            instance_id: instance_id.into(),
+            result,
+            model_patch: model_patch.into(),
+            resource_usage,
+        });
+    }
+
+    /// Number of instances recorded.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether any instances have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Fraction of instances that were fully solved.
+    pub fn resolve_rate(&self) -> f64 {
+        if self.entries.is_empty() {
+            return 0.0;
+        }
+        let solved = self.entries.iter().filter(|e| e.result.is_solved()).count();
+        solved as f64 / self.entries.len() as f64
+    }
+
+    /// Mean FAIL_TO_PASS pass rate across all instances.
+    pub fn mean_fail_to_pass_rate(&self) -> f64 {
+        if self.entries.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = self.entries.iter().map(|e| e.result.fail_to_pass_rate()).sum();
+        total / self.entries.len() as f64
+    }
+
+    /// Number of instances with at least one PASS_TO_PASS regression.
+    pub fn regression_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| {
+                matches!(&e.result, SWEBenchResult::Failed { regressions, .. } if *regressions > 0)
+            })
+            .count()
+    }
+
+    /// Per-repository breakdown, keyed by repo owner (e.g. "django" for
+    /// instance id "django__django-11133"), in a stable (sorted) order.
+    pub fn per_repo_breakdown(&self) -> BTreeMap<String, RepoBreakdown> {
+        let mut breakdown: BTreeMap<String, RepoBreakdown> = BTreeMap::new();
+        for entry in &self.entries {
+            let stats = breakdown
+                .entry(repo_owner_from_instance_id(&entry.instance_id).to_string())
+                .or_default();
+            stats.total += 1;
+            if entry.result.is_solved() {
+                stats.solved += 1;
+            }
+        }
+        breakdown
+    }
+
+    /// Aggregate resource cost across every instance that reported usage.
+    ///
+    /// Lets callers compare agent efficiency and spot runs dominated by a
+    /// few pathological, resource-hungry instances.
+    pub fn resource_usage(&self) -> RunResourceUsage {
+        let mut aggregate = RunResourceUsage::default();
+        for usage in self.entries.iter().filter_map(|e| e.resource_usage) {
+            aggregate.max_peak_rss_bytes = aggregate.max_peak_rss_bytes.max(usage.peak_rss_bytes);
+            aggregate.total_cpu_seconds += usage.cpu_seconds;
+            aggregate.total_wall_ms += usage.wall_ms;
+            aggregate.sample_count += 1;
+        }
+        aggregate
+    }
+
+    /// Serialize to the official SWE-bench `predictions.jsonl` shape: one
+    /// `{instance_id, model_name_or_path, model_patch}` object per line, so
+    /// the run can be scored by the upstream harness.
+    pub fn to_predictions_jsonl(&self, model_name_or_path: &str) -> String {
+        let mut out = String::new();
+        for entry in &self.entries {
+            let prediction = Prediction {
+                instance_id: &entry.instance_id,
+                model_name_or_path,
+                model_patch: &entry.model_patch,
+            };
+            if let Ok(line) = serde_json::to_string(&prediction) {
+                out.push_str(&line);
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Print a human-readable summary: overall stats, counts per
+    /// solved/partial/failed/error tier, and a per-repo breakdown.
+    pub fn print_summary(&self) {
+        let mut solved = 0;
+        let mut partial = 0;
+        let mut failed = 0;
+        let mut error = 0;
+
+        for entry in &self.entries {
+            match &entry.result {
+                SWEBenchResult::Solved => solved += 1,
+                SWEBenchResult::Partial { .. } => partial += 1,
+                SWEBenchResult::Failed { .. } => failed += 1,
+                SWEBenchResult::Error { .. } => error += 1,
+            }
+        }
+
+        println!("SWE-bench Run Summary");
+        println!("======================");
+        println!("Instances: {}", self.entries.len());
+        println!("Resolve rate: {:.1}%", self.resolve_rate() * 100.0);
+        println!(
+            "Mean FAIL_TO_PASS rate: {:.1}%",
+            self.mean_fail_to_pass_rate() * 100.0
+        );
+        println!("Regressions: {}", self.regression_count());
+        println!();
+
+        let usage = self.resource_usage();
+        if usage.sample_count > 0 {
+            println!(
+                "Resource usage ({} instances sampled):",
+                usage.sample_count
+            );
+            println!(
+                "  Peak RSS (max):  {:.1} MB",
+                usage.max_peak_rss_bytes as f64 / (1024.0 * 1024.0)
+            );
+            println!("  CPU time (sum):  {:.1}s", usage.total_cpu_seconds);
+            println!(
+                "  Wall time (sum): {:.1}s",
+                usage.total_wall_ms as f64 / 1000.0
+            );
+            println!();
+        }
+        println!("Solved:  {}", solved);
+        println!("Partial: {}", partial);
+        println!("Failed:  {}", failed);
+        println!("Error:   {}", error);
+        println!();
+        println!("Per-repo breakdown:");
+        for (owner, stats) in self.per_repo_breakdown() {
+            println!("  {:<20} {}/{} solved", owner, stats.solved, stats.total);
+        }
+    }
+}
+
+/// Extract the repo owner from an instance id like "django__django-11133"
+/// (mirrors `SWEBenchInstance::repo_owner()`, for callers that only have the
+/// instance id on hand, not the full instance).
+fn repo_owner_from_instance_id(instance_id: &str) -> &str {
+    instance_id.split("__").next().unwrap_or(instance_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved() -> SWEBenchResult {
+        SWEBenchResult::Solved
+    }
+
+    fn partial() -> SWEBenchResult {
+        SWEBenchResult::Partial {
+            fail_to_pass_passed: 1,
+            fail_to_pass_total: 2,
+        }
+    }
+
+    fn failed_with_regression() -> SWEBenchResult {
+        SWEBenchResult::Failed {
+            reason: "regression".to_string(),
+            fail_to_pass_passed: 1,
+            fail_to_pass_total: 1,
+            regressions: 2,
+        }
+    }
+
+    #[test]
+    fn test_resolve_rate() {
+        let mut run = SWEBenchRun::new();
+        run.add("django__django-1", solved(), "diff a", None);
+        run.add("django__django-2", partial(), "diff b", None);
+        assert_eq!(run.resolve_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_regression_count() {
+        let mut run = SWEBenchRun::new();
+        run.add("astropy__astropy-1", solved(), "diff a", None);
+        run.add("astropy__astropy-2", failed_with_regression(), "diff b", None);
+        assert_eq!(run.regression_count(), 1);
+    }
+
+    #[test]
+    fn test_per_repo_breakdown() {
+        let mut run = SWEBenchRun::new();
+        run.add("django__django-1", solved(), "", None);
+        run.add("django__django-2", partial(), "", None);
+        run.add("astropy__astropy-1", solved(), "", None);
+
+        let breakdown = run.per_repo_breakdown();
+        assert_eq!(breakdown["django"].solved, 1);
+        assert_eq!(breakdown["django"].total, 2);
+        assert_eq!(breakdown["astropy"].solved, 1);
+        assert_eq!(breakdown["astropy"].total, 1);
+    }
+
+    #[test]
+    fn test_predictions_jsonl() {
+        let mut run = SWEBenchRun::new();
+        run.add(
+            "django__django-1",
+            solved(),
+            "diff --git a/foo.py ...",
+            None,
+        );
+
+        let jsonl = run.to_predictions_jsonl("qbit-agent");
+        assert!(jsonl.contains("\"instance_id\":\"django__django-1\""));
+        assert!(jsonl.contains("\"model_name_or_path\":\"qbit-agent\""));
+        assert!(jsonl.contains("\"model_patch\":\"diff --git a/foo.py ...\""));
+    }
+
+    #[test]
+    fn test_resource_usage_aggregates_reporting_instances_only() {
+        let mut run = SWEBenchRun::new();
+        run.add(
+            "django__django-1",
+            solved(),
+            "",
+            Some(ResourceUsage {
+                peak_rss_bytes: 100,
+                cpu_seconds: 2.0,
+                wall_ms: 1000,
+            }),
+        );
+        run.add(
+            "django__django-2",
+            solved(),
+            "",
+            Some(ResourceUsage {
+                peak_rss_bytes: 300,
+                cpu_seconds: 3.0,
+                wall_ms: 1500,
+            }),
+        );
+        // No resource usage reported for this instance - shouldn't count
+        // toward the sample count or skew the aggregate.
+        run.add("astropy__astropy-1", solved(), "", None);
+
+        let usage = run.resource_usage();
+        assert_eq!(usage.sample_count, 2);
+        assert_eq!(usage.max_peak_rss_bytes, 300);
+        assert_eq!(usage.total_cpu_seconds, 5.0);
+        assert_eq!(usage.total_wall_ms, 2500);
+    }
+}
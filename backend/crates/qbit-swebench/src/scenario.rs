@@ -10,6 +10,7 @@ use qbit_evals::runner::EvalRunner;
 use qbit_evals::scenarios::Scenario;
 use tracing::{debug, info};
 
+use crate::backend::{self, ExecutionBackend};
 use crate::docker::DockerExecutor;
 use crate::metric::SWEBenchTestMetric;
 use crate::repo::RepoManager;
@@ -17,7 +18,20 @@ use crate::tools::{
     clear_active_container, execute_swebench_test_tool, get_swebench_test_tool_definition,
     set_active_context, SWEBenchContext,
 };
-use crate::types::SWEBenchInstance;
+use crate::types::{ResourceCeiling, SWEBenchInstance};
+
+/// Default resource ceiling applied to every instance's test execution (see
+/// [`ResourceCeiling`]). Generous enough not to trip on a normal SWE-bench
+/// test suite, but tight enough to flag an agent-induced runaway (e.g.
+/// runaway memory use, or a fork bomb from a broken test fixture). It is
+/// checked after the test run has finished and does not stop a run early:
+/// an instance over the ceiling is reported as an error instead of being
+/// scored as an ordinary failure.
+const DEFAULT_RESOURCE_CEILING: ResourceCeiling = ResourceCeiling {
+    max_peak_rss_bytes: Some(8 * 1024 * 1024 * 1024),
+    max_cpu_seconds: Some(1800.0),
+    max_wall_ms: Some(20 * 60 * 1000),
+};
 
 /// Strip ANSI escape codes for display.
 fn strip_ansi_for_display(s: &str) -> String {
@@ -42,6 +56,38 @@ fn strip_ansi_for_display(s: &str) -> String {
     result
 }
 
+/// Merge a passing burn-in run (FAIL_TO_PASS + the affected PASS_TO_PASS
+/// subset) with the subsequent run of the deferred PASS_TO_PASS subset into
+/// a single result covering the full instance, without re-running
+/// FAIL_TO_PASS a second time.
+fn merge_burn_in_results(
+    burn_in: crate::types::TestExecutionResult,
+    deferred: crate::types::TestExecutionResult,
+) -> crate::types::TestExecutionResult {
+    let mut pass_to_pass_results = burn_in.pass_to_pass_results;
+    pass_to_pass_results.extend(deferred.pass_to_pass_results);
+
+    let resource_usage = match (burn_in.resource_usage, deferred.resource_usage) {
+        (Some(a), Some(b)) => Some(crate::types::ResourceUsage {
+            peak_rss_bytes: a.peak_rss_bytes.max(b.peak_rss_bytes),
+            cpu_seconds: a.cpu_seconds + b.cpu_seconds,
+            wall_ms: a.wall_ms + b.wall_ms,
+        }),
+        (a, b) => a.or(b),
+    };
+
+    crate::types::TestExecutionResult {
+        execution_success: burn_in.execution_success && deferred.execution_success,
+        exit_code: deferred.exit_code,
+        stdout: format!("{}\n{}", burn_in.stdout, deferred.stdout),
+        stderr: format!("{}\n{}", burn_in.stderr, deferred.stderr),
+        fail_to_pass_results: burn_in.fail_to_pass_results,
+        pass_to_pass_results,
+        duration_ms: burn_in.duration_ms + deferred.duration_ms,
+        resource_usage,
+    }
+}
+
 /// Scenario for a single SWE-bench instance.
 pub struct SWEBenchScenario {
     /// The SWE-bench instance
@@ -486,12 +532,67 @@ impl Scenario for SWEBenchScenario {
         eprintln!("        FAIL_TO_PASS tests: {:?}", self.instance.fail_to_pass_tests());
         eprintln!("        PASS_TO_PASS tests: {} total", self.instance.pass_to_pass_tests().len());
 
-        // Execute tests
-        // Pass the parent workspace directory, not repo_path, because Docker mounts
-        // workspace at /workspace and expects the repo at /workspace/repo
-        let test_result = match docker.run_tests(&self.instance, &workspace).await {
+        // Burn-in: run FAIL_TO_PASS plus only the likely-affected PASS_TO_PASS
+        // subset first (through the `ExecutionBackend` abstraction, attached
+        // to the workspace the agent already edited in place - the live-
+        // container flow above stays on `DockerExecutor` directly,
+        // `ExecutionBackend` doesn't yet model exposing a running container
+        // to a concurrently executing agent turn). Since the affected subset
+        // is a subset of the full PASS_TO_PASS set, a regression there is
+        // necessarily a regression in the full set too, so a failing burn-in
+        // lets the (often much larger) deferred subset be skipped entirely.
+        // A passing burn-in means FAIL_TO_PASS is already confirmed and only
+        // the deferred subset still needs running - not the full set again -
+        // with its results merged into the burn-in's for a complete account.
+        let modified_paths: Vec<String> = modified_files
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+        let (affected, deferred) = self.instance.select_affected_tests(&modified_paths);
+
+        let test_result = if !affected.is_empty() && !deferred.is_empty() {
+            eprintln!(
+                "        Burn-in: checking {} likely-affected PASS_TO_PASS test(s) before the remaining {}...",
+                affected.len(),
+                deferred.len()
+            );
+            let mut burn_in_instance = self.instance.clone();
+            burn_in_instance.pass_to_pass = serde_json::to_string(&affected).unwrap_or_default();
+            let mut burn_in_backend =
+                backend::DockerBackend::attached(burn_in_instance, workspace.clone())?;
+
+            match burn_in_backend.run_command("").await {
+                Ok(burn_in_result)
+                    if !burn_in_result.fail_to_pass_success()
+                        || !burn_in_result.pass_to_pass_success() =>
+                {
+                    eprintln!(
+                        "        Burn-in subset already failing - skipping the remaining PASS_TO_PASS tests"
+                    );
+                    Ok(burn_in_result)
+                }
+                Ok(burn_in_result) => {
+                    let mut deferred_instance = self.instance.clone();
+                    deferred_instance.fail_to_pass = "[]".to_string();
+                    deferred_instance.pass_to_pass = serde_json::to_string(&deferred).unwrap_or_default();
+                    let mut deferred_backend =
+                        backend::DockerBackend::attached(deferred_instance, workspace.clone())?;
+                    deferred_backend
+                        .run_command("")
+                        .await
+                        .map(|deferred_result| merge_burn_in_results(burn_in_result, deferred_result))
+                }
+                Err(e) => Err(e),
+            }
+        } else {
+            let mut test_backend = backend::DockerBackend::attached(self.instance.clone(), workspace.clone())?;
+            test_backend.run_command("").await
+        };
+
+        let test_result = match test_result {
             Ok(result) => result,
             Err(e) => {
+                let e = anyhow::Error::from(e);
                 let err_msg = e.to_string();
                 // Check if this is a missing image error - skip gracefully
                 if err_msg.contains("IMAGE_NOT_AVAILABLE") {
@@ -506,6 +607,19 @@ impl Scenario for SWEBenchScenario {
             }
         };
 
+        // Treat a resource blowout (runaway memory/CPU/wall time) as an error
+        // rather than scoring it as an ordinary test failure.
+        if let Some(crate::types::SWEBenchResult::Error { message }) =
+            test_result.check_resource_ceiling(&DEFAULT_RESOURCE_CEILING)
+        {
+            eprintln!("  ⚠ Resource ceiling exceeded: {}", message);
+            return Ok(self.create_error_report(
+                &agent_output,
+                start.elapsed().as_millis() as u64,
+                &message,
+            ));
+        }
+
         info!(
             "Test results for {}: execution_success={}, exit_code={}, FAIL_TO_PASS={}/{}, PASS_TO_PASS={}/{}",
             self.instance.instance_id,
@@ -213,6 +213,90 @@ impl SWEBenchInstance {
             _ => TestRunner::Pytest,
         }
     }
+
+    /// Split `pass_to_pass_tests()` into a likely-affected subset and a
+    /// deferred remainder, based on which source files `changed_paths`
+    /// touched.
+    ///
+    /// This powers a "burn-in" run order for regression checking: run the
+    /// affected subset first, and only fall through to the (often much
+    /// larger) deferred set if that subset passes. `SWEBenchScenario` uses
+    /// this order for every instance whose split leaves both halves
+    /// non-empty; no setting turns it off. It's a plausibility
+    /// heuristic, not a real import graph - for pytest repos it matches
+    /// changed file stems against test file names (`foo.py` affects
+    /// `test_foo.py`/`foo_test.py`); for Django's dotted test format it maps
+    /// changed app directories to that app's `app.tests.*` prefix.
+    ///
+    /// `TestExecutionResult::pass_to_pass_success()` only checks the results
+    /// it holds, not the full `pass_to_pass_tests()` list, so a result that
+    /// covers the affected subset alone can still report `is_solved()`.
+    /// Callers must execute the deferred set and merge both results into
+    /// `pass_to_pass_results` before calling `is_solved()`; a failing
+    /// affected subset already rules out `is_solved()`, so the deferred set
+    /// can be skipped in that case.
+    pub fn select_affected_tests(&self, changed_paths: &[String]) -> (Vec<String>, Vec<String>) {
+        let stems: Vec<String> = changed_paths.iter().filter_map(|p| module_stem(p)).collect();
+        let apps: Vec<String> = changed_paths
+            .iter()
+            .filter_map(|p| top_level_component(p))
+            .collect();
+
+        if stems.is_empty() && apps.is_empty() {
+            return (Vec::new(), self.pass_to_pass_tests());
+        }
+
+        let is_django = self.uses_django_test_format();
+        let mut affected = Vec::new();
+        let mut deferred = Vec::new();
+
+        for test in self.pass_to_pass_tests() {
+            let plausible = if is_django {
+                apps.iter()
+                    .any(|app| test.starts_with(&format!("{app}.tests")))
+            } else {
+                stems.iter().any(|stem| pytest_node_mentions_stem(&test, stem))
+            };
+
+            if plausible {
+                affected.push(test);
+            } else {
+                deferred.push(test);
+            }
+        }
+
+        (affected, deferred)
+    }
+}
+
+/// Extract a plausible module "stem" for pytest-style matching from a
+/// changed file path, e.g. `"src/pkg/foo.py"` -> `"foo"`.
+fn module_stem(path: &str) -> Option<String> {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let stem = file_name.strip_suffix(".py")?;
+    (!stem.is_empty()).then(|| stem.to_string())
+}
+
+/// Extract the top-level path component (a Django app directory, typically)
+/// from a changed file path, e.g. `"admin_views/models.py"` -> `"admin_views"`.
+fn top_level_component(path: &str) -> Option<String> {
+    let component = path.split('/').next()?;
+    (!component.is_empty() && !component.ends_with(".py")).then(|| component.to_string())
+}
+
+/// Whether a pytest node id plausibly exercises `stem`: its test file is
+/// named `test_<stem>.py`, `<stem>_test.py`, or `<stem>.py` itself.
+fn pytest_node_mentions_stem(test: &str, stem: &str) -> bool {
+    let test_file = test.split("::").next().unwrap_or(test);
+    let test_file_name = test_file.rsplit('/').next().unwrap_or(test_file);
+    match test_file_name.strip_suffix(".py") {
+        Some(test_stem) => {
+            test_stem == format!("test_{stem}")
+                || test_stem == format!("{stem}_test")
+                || test_stem == stem
+        }
+        None => test.contains(stem),
+    }
 }
 
 /// Test runner type for a repository.
@@ -254,6 +338,39 @@ pub struct TestExecutionResult {
 
     /// Execution time in milliseconds
     pub duration_ms: u64,
+
+    /// Resource consumption sampled during this execution, if the backend
+    /// that ran it was able to capture one (see [`ResourceUsage`]).
+    pub resource_usage: Option<ResourceUsage>,
+}
+
+/// Resource consumption sampled during a single test execution.
+///
+/// Captured by whichever backend ran the tests - `DockerExecutor` samples it
+/// from the container's cgroup stats, a local backend samples `/proc/<pid>` -
+/// so it's optional on [`TestExecutionResult`] for backends that can't
+/// observe it.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// Peak resident set size, in bytes.
+    pub peak_rss_bytes: u64,
+    /// Total CPU time consumed (user + system), in seconds.
+    pub cpu_seconds: f64,
+    /// Wall-clock time the execution took, in milliseconds.
+    pub wall_ms: u64,
+}
+
+/// Per-instance resource ceilings for [`TestExecutionResult::check_resource_ceiling`].
+///
+/// Any field left `None` is not enforced.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResourceCeiling {
+    /// Maximum peak RSS, in bytes.
+    pub max_peak_rss_bytes: Option<u64>,
+    /// Maximum CPU time, in seconds.
+    pub max_cpu_seconds: Option<f64>,
+    /// Maximum wall-clock time, in milliseconds.
+    pub max_wall_ms: Option<u64>,
 }
 
 impl TestExecutionResult {
@@ -291,6 +408,89 @@ impl TestExecutionResult {
             .count();
         (passed, self.pass_to_pass_results.len())
     }
+
+    /// Check the captured resource usage against `ceiling`.
+    ///
+    /// Returns `Some(SWEBenchResult::Error { .. })` describing the first
+    /// ceiling exceeded, so callers can short-circuit scoring for a
+    /// pathological instance instead of treating a resource blowout as an
+    /// ordinary test failure. Returns `None` when usage wasn't captured
+    /// (`resource_usage` is `None`) or is within every configured bound.
+    pub fn check_resource_ceiling(&self, ceiling: &ResourceCeiling) -> Option<SWEBenchResult> {
+        let usage = self.resource_usage?;
+
+        if let Some(max) = ceiling.max_peak_rss_bytes {
+            if usage.peak_rss_bytes > max {
+                return Some(SWEBenchResult::Error {
+                    message: format!(
+                        "peak RSS {} bytes exceeded ceiling of {} bytes",
+                        usage.peak_rss_bytes, max
+                    ),
+                });
+            }
+        }
+        if let Some(max) = ceiling.max_cpu_seconds {
+            if usage.cpu_seconds > max {
+                return Some(SWEBenchResult::Error {
+                    message: format!(
+                        "CPU time {:.1}s exceeded ceiling of {:.1}s",
+                        usage.cpu_seconds, max
+                    ),
+                });
+            }
+        }
+        if let Some(max) = ceiling.max_wall_ms {
+            if usage.wall_ms > max {
+                return Some(SWEBenchResult::Error {
+                    message: format!(
+                        "wall time {}ms exceeded ceiling of {}ms",
+                        usage.wall_ms, max
+                    ),
+                });
+            }
+        }
+        None
+    }
+
+    /// Classify this execution into a [`SWEBenchResult`] for run-level
+    /// aggregation (see [`crate::run::SWEBenchRun`]).
+    ///
+    /// Returns `Error` if execution timed out or failed to start
+    /// (`execution_success` is false and `exit_code` is -1), `Solved` if every
+    /// FAIL_TO_PASS test passes with no regressions, `Partial` if some but not
+    /// all FAIL_TO_PASS tests pass with no regressions, and `Failed`
+    /// otherwise.
+    pub fn to_swebench_result(&self) -> SWEBenchResult {
+        if !self.execution_success && self.exit_code == -1 {
+            return SWEBenchResult::Error {
+                message: "test execution timed out or failed to start".to_string(),
+            };
+        }
+
+        let (f2p_passed, f2p_total) = self.fail_to_pass_count();
+        let (p2p_passed, p2p_total) = self.pass_to_pass_count();
+        let regressions = p2p_total - p2p_passed;
+
+        if regressions == 0 && f2p_passed == f2p_total {
+            SWEBenchResult::Solved
+        } else if regressions == 0 && f2p_passed > 0 {
+            SWEBenchResult::Partial {
+                fail_to_pass_passed: f2p_passed,
+                fail_to_pass_total: f2p_total,
+            }
+        } else {
+            SWEBenchResult::Failed {
+                reason: if regressions > 0 {
+                    format!("{} PASS_TO_PASS regressions", regressions)
+                } else {
+                    "no FAIL_TO_PASS tests pass".to_string()
+                },
+                fail_to_pass_passed: f2p_passed,
+                fail_to_pass_total: f2p_total,
+                regressions,
+            }
+        }
+    }
 }
 
 /// Result of a single test.
@@ -456,6 +656,7 @@ mod tests {
                 duration_ms: Some(75),
             }],
             duration_ms: 225,
+            resource_usage: None,
         };
 
         assert!(result.fail_to_pass_success());
@@ -464,4 +665,146 @@ mod tests {
         assert_eq!(result.fail_to_pass_count(), (2, 2));
         assert_eq!(result.pass_to_pass_count(), (1, 1));
     }
+
+    #[test]
+    fn test_select_affected_tests_pytest() {
+        let instance = SWEBenchInstance {
+            instance_id: "astropy__astropy-1".to_string(),
+            repo: "astropy/astropy".to_string(),
+            base_commit: "abc123".to_string(),
+            problem_statement: "Test".to_string(),
+            patch: "".to_string(),
+            test_patch: "".to_string(),
+            fail_to_pass: "[]".to_string(),
+            pass_to_pass: r#"["tests/test_wcs.py::test_basic", "tests/test_io.py::test_read"]"#
+                .to_string(),
+            version: "3.0".to_string(),
+            environment_setup_commit: "def456".to_string(),
+            hints_text: None,
+            created_at: None,
+        };
+
+        let (affected, deferred) =
+            instance.select_affected_tests(&["astropy/wcs/wcs.py".to_string()]);
+
+        assert_eq!(affected, vec!["tests/test_wcs.py::test_basic"]);
+        assert_eq!(deferred, vec!["tests/test_io.py::test_read"]);
+    }
+
+    #[test]
+    fn test_select_affected_tests_django() {
+        let instance = SWEBenchInstance {
+            instance_id: "django__django-11133".to_string(),
+            repo: "django/django".to_string(),
+            base_commit: "abc123".to_string(),
+            problem_statement: "Test".to_string(),
+            patch: "".to_string(),
+            test_patch: "".to_string(),
+            fail_to_pass: "[]".to_string(),
+            pass_to_pass: r#"["admin_views.tests.TestClass.test_a", "auth.tests.TestClass.test_b"]"#
+                .to_string(),
+            version: "3.0".to_string(),
+            environment_setup_commit: "def456".to_string(),
+            hints_text: None,
+            created_at: None,
+        };
+
+        let (affected, deferred) =
+            instance.select_affected_tests(&["admin_views/views.py".to_string()]);
+
+        assert_eq!(affected, vec!["admin_views.tests.TestClass.test_a"]);
+        assert_eq!(deferred, vec!["auth.tests.TestClass.test_b"]);
+    }
+
+    #[test]
+    fn test_select_affected_tests_no_changed_paths_defers_everything() {
+        let instance = SWEBenchInstance {
+            instance_id: "astropy__astropy-1".to_string(),
+            repo: "astropy/astropy".to_string(),
+            base_commit: "abc123".to_string(),
+            problem_statement: "Test".to_string(),
+            patch: "".to_string(),
+            test_patch: "".to_string(),
+            fail_to_pass: "[]".to_string(),
+            pass_to_pass: r#"["tests/test_wcs.py::test_basic"]"#.to_string(),
+            version: "3.0".to_string(),
+            environment_setup_commit: "def456".to_string(),
+            hints_text: None,
+            created_at: None,
+        };
+
+        let (affected, deferred) = instance.select_affected_tests(&[]);
+        assert!(affected.is_empty());
+        assert_eq!(deferred, vec!["tests/test_wcs.py::test_basic"]);
+    }
+
+    fn result_with_usage(usage: ResourceUsage) -> TestExecutionResult {
+        TestExecutionResult {
+            execution_success: true,
+            exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+            fail_to_pass_results: vec![],
+            pass_to_pass_results: vec![],
+            duration_ms: usage.wall_ms,
+            resource_usage: Some(usage),
+        }
+    }
+
+    #[test]
+    fn test_check_resource_ceiling_within_bounds_returns_none() {
+        let result = result_with_usage(ResourceUsage {
+            peak_rss_bytes: 1_000_000,
+            cpu_seconds: 5.0,
+            wall_ms: 2_000,
+        });
+        let ceiling = ResourceCeiling {
+            max_peak_rss_bytes: Some(2_000_000),
+            max_cpu_seconds: Some(10.0),
+            max_wall_ms: Some(5_000),
+        };
+
+        assert!(result.check_resource_ceiling(&ceiling).is_none());
+    }
+
+    #[test]
+    fn test_check_resource_ceiling_rss_overrun_returns_error() {
+        let result = result_with_usage(ResourceUsage {
+            peak_rss_bytes: 5_000_000,
+            cpu_seconds: 1.0,
+            wall_ms: 100,
+        });
+        let ceiling = ResourceCeiling {
+            max_peak_rss_bytes: Some(1_000_000),
+            ..Default::default()
+        };
+
+        match result.check_resource_ceiling(&ceiling) {
+            Some(SWEBenchResult::Error { message }) => {
+                assert!(message.contains("peak RSS"));
+            }
+            other => panic!("expected a ceiling violation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_resource_ceiling_no_usage_captured_returns_none() {
+        let result = TestExecutionResult {
+            execution_success: true,
+            exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+            fail_to_pass_results: vec![],
+            pass_to_pass_results: vec![],
+            duration_ms: 100,
+            resource_usage: None,
+        };
+
+        let ceiling = ResourceCeiling {
+            max_cpu_seconds: Some(0.0),
+            ..Default::default()
+        };
+
+        assert!(result.check_resource_ceiling(&ceiling).is_none());
+    }
 }
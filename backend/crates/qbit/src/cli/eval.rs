@@ -1127,7 +1127,7 @@ pub async fn run_openai_model_tests(
         .try_init();
 
     let scenarios = if let Some(model_id) = model_filter {
-        match get_openai_model_scenario(model_id) {
+        match get_openai_model_scenario(model_id).await {
             Some(s) => vec![s],
             None => {
                 eprintln!("Unknown OpenAI model: {}", model_id);
@@ -1139,7 +1139,7 @@ pub async fn run_openai_model_tests(
             }
         }
     } else {
-        openai_model_scenarios()
+        openai_model_scenarios().await
     };
 
     if !json_output {
@@ -1382,6 +1382,17 @@ pub async fn run_swebench(
 
         let result = qbit_swebench::run_tests_only(instance_id, &workspace).await?;
 
+        // Run-level aggregation (resolve rate, per-repo breakdown, etc.) only
+        // makes sense across many instances, but printing a single-entry
+        // `SWEBenchRun` summary here gives this path resource-usage
+        // accounting, beyond just the solved/failed line below. The full
+        // benchmark run further down reports through `EvalSummary` and
+        // prints no resource usage. There's no model patch to
+        // record in test-only mode since the workspace was already patched.
+        let mut run = qbit_swebench::SWEBenchRun::new();
+        run.add(instance_id, result.to_swebench_result(), "", result.resource_usage);
+        run.print_summary();
+
         // Print final result
         if result.is_solved() {
             println!("{}", color::green_line());